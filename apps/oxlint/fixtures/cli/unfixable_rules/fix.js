@@ -0,0 +1 @@
+var x = new String('Hello world');
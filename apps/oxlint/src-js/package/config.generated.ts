@@ -781,6 +781,15 @@ export interface OxlintOptions {
    * Equivalent to passing `--deny-warnings` on the CLI.
    */
   denyWarnings?: boolean;
+  /**
+   * Additional comment prefixes that suppress diagnostics on the same line,
+   * the same way `oxlint-disable-line` does.
+   *
+   * For example, with `["oxlint-ignore"]`, `debugger; // oxlint-ignore no-debugger`
+   * behaves like `debugger; // oxlint-disable-line no-debugger`.
+   * Only supported in the root configuration file.
+   */
+  ignoreCommentPrefixes?: string[];
   /**
    * Specify a warning threshold. Exits with an error status if warnings exceed this value.
    *
@@ -819,6 +828,15 @@ export interface OxlintOptions {
    * Note that this requires the `oxlint-tsgolint` package to be installed.
    */
   typeCheck?: boolean;
+  /**
+   * Rules whose fixes are never applied, even when fixing is enabled.
+   * Diagnostics from these rules are still reported.
+   *
+   * Rule names are in the same format as in `rules`, e.g. `no-debugger` or `unicorn/no-null`.
+   * Equivalent to passing `--unfixable-rule` on the CLI. Rules from both are combined.
+   * Only supported in the root configuration file.
+   */
+  unfixableRules?: string[];
 }
 export interface OxlintOverride {
   /**
@@ -1348,7 +1366,7 @@ export interface DummyRuleMap {
   "react/no-array-index-key"?: RuleNoConfig;
   "react/no-children-prop"?: RuleNoConfig;
   "react/no-clone-element"?: RuleNoConfig;
-  "react/no-danger"?: RuleNoConfig;
+  "react/no-danger"?: RuleNoConfig | [AllowWarnDeny, NoDangerConfig];
   "react/no-danger-with-children"?: RuleNoConfig;
   "react/no-did-mount-set-state"?: RuleNoConfig | [AllowWarnDeny, AllowedOrDisallowInFunc];
   "react/no-did-update-set-state"?: RuleNoConfig | [AllowWarnDeny, AllowedOrDisallowInFunc];
@@ -4725,6 +4743,13 @@ export interface JsxPropsNoSpreadingConfig {
    */
   html?: IgnoreEnforceOption;
 }
+export interface NoDangerConfig {
+  /**
+   * Names of custom components to check, in addition to DOM elements.
+   * Glob patterns are supported, e.g. `["*"]` checks all components.
+   */
+  customComponentNames?: string[];
+}
 export interface NoMultiCompConfig {
   /**
    * When `true`, the rule will ignore stateless components and will allow you to have multiple
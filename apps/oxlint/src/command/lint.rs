@@ -230,6 +230,11 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Never apply fixes of the rule NAME, but still report its diagnostics.
+    /// Can be passed multiple times.
+    #[bpaf(long("unfixable-rule"), argument("NAME"), many, hide_usage)]
+    pub unfixable_rules: Vec<String>,
 }
 
 impl FixOptions {
@@ -458,6 +458,18 @@ impl<'a> ConfigLoader<'a> {
                     ));
                     continue;
                 }
+                if options.ignore_comment_prefixes.is_some() {
+                    errors.push(ConfigLoadError::Diagnostic(
+                        nested_ignore_comment_prefixes_not_supported(&path),
+                    ));
+                    continue;
+                }
+                if options.unfixable_rules.is_some() {
+                    errors.push(ConfigLoadError::Diagnostic(nested_unfixable_rules_not_supported(
+                        &path,
+                    )));
+                    continue;
+                }
             }
 
             let builder = match ConfigStoreBuilder::from_oxlintrc(
@@ -753,6 +765,22 @@ fn nested_respect_eslint_disable_directives_not_supported(path: &Path) -> OxcDia
     .with_help("Move `options.respectEslintDisableDirectives` to the root configuration file.")
 }
 
+fn nested_ignore_comment_prefixes_not_supported(path: &Path) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "The `options.ignoreCommentPrefixes` option is only supported in the root config, but it was found in {}.",
+        path.display()
+    ))
+    .with_help("Move `options.ignoreCommentPrefixes` to the root configuration file.")
+}
+
+fn nested_unfixable_rules_not_supported(path: &Path) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "The `options.unfixableRules` option is only supported in the root config, but it was found in {}.",
+        path.display()
+    ))
+    .with_help("Move `options.unfixableRules` to the root configuration file.")
+}
+
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
@@ -949,6 +977,45 @@ mod test {
         assert!(matches!(errors[0], ConfigLoadError::Diagnostic(_)));
     }
 
+    #[test]
+    fn test_nested_json_config_rejects_ignore_comment_prefixes() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let nested_path = root_dir.path().join("nested/.oxlintrc.json");
+        std::fs::create_dir_all(nested_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &nested_path,
+            r#"{ "options": { "ignoreCommentPrefixes": ["oxlint-ignore"] } }"#,
+        )
+        .unwrap();
+
+        let mut external_plugin_store = ExternalPluginStore::new(false);
+        let mut loader = ConfigLoader::new(None, &mut external_plugin_store, &[], None);
+        let (_configs, errors) = loader.load_discovered_with_root_dir(
+            root_dir.path(),
+            [DiscoveredConfigFile::Json(nested_path)],
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ConfigLoadError::Diagnostic(_)));
+    }
+
+    #[test]
+    fn test_nested_json_config_rejects_unfixable_rules() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let nested_path = root_dir.path().join("nested/.oxlintrc.json");
+        std::fs::create_dir_all(nested_path.parent().unwrap()).unwrap();
+        std::fs::write(&nested_path, r#"{ "options": { "unfixableRules": ["no-debugger"] } }"#)
+            .unwrap();
+
+        let mut external_plugin_store = ExternalPluginStore::new(false);
+        let mut loader = ConfigLoader::new(None, &mut external_plugin_store, &[], None);
+        let (_configs, errors) = loader.load_discovered_with_root_dir(
+            root_dir.path(),
+            [DiscoveredConfigFile::Json(nested_path)],
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ConfigLoadError::Diagnostic(_)));
+    }
+
     #[test]
     fn test_nested_json_config_allows_type_aware_from_extends() {
         let root_dir = tempfile::tempdir().unwrap();
@@ -434,6 +434,7 @@ impl CliRunner {
 
         let linter = Linter::new(LintOptions::default(), config_store, external_linter)
             .with_fix(fix_options.fix_kind())
+            .with_unfixable_rules(&fix_options.unfixable_rules)
             .with_report_unused_directives(report_unused_directives);
 
         let number_of_files = files_to_lint.len();
@@ -1159,6 +1160,16 @@ mod test {
         tester.test_fix("skip_suggestion.vue", test_2, test_2);
     }
 
+    #[test]
+    fn test_fix_unfixable_rule() {
+        let tester = Tester::new().with_cwd("fixtures/cli/fix_argument".into());
+        let test = "var x = new String('Hello world');\n";
+        tester.test_fix_with_args("fix.js", test, test, &["--unfixable-rule", "no-new-wrappers"]);
+
+        let tester = Tester::new().with_cwd("fixtures/cli/unfixable_rules".into());
+        tester.test_fix("fix.js", test, test);
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
@@ -373,4 +373,34 @@ mod tests {
         .unwrap();
         assert_eq!(action.kind, Some(CODE_ACTION_KIND_SOURCE_FIX_ALL_DANGEROUS_OXC));
     }
+
+    #[test]
+    fn test_apply_fix_code_actions_uses_utf16_ranges() {
+        use oxc_diagnostics::OxcDiagnostic;
+        use oxc_linter::{Fix, Message, PossibleFixes};
+        use oxc_span::Span;
+
+        use crate::lsp::error_with_position::message_to_lsp_diagnostic;
+
+        // `debugger;` is at bytes 16..25, but UTF-16 characters 14..23, because the emoji is
+        // 4 bytes in UTF-8 and 2 code units in UTF-16
+        let source = "let s = '😀'; debugger;";
+        let span = Span::new(16, 25);
+        let message = Message::new(
+            OxcDiagnostic::warn("`debugger` statement is not allowed").with_label(span),
+            PossibleFixes::Single(Fix::delete(span).with_message("Remove the debugger statement")),
+        );
+        let uri = Uri::from_str("file:///test.js").unwrap();
+
+        let report = message_to_lsp_diagnostic(message, &uri, source, None).unwrap();
+        let expected_range = Range::new(Position::new(0, 14), Position::new(0, 23));
+        assert_eq!(report.diagnostic.range, expected_range);
+
+        let code_actions = apply_fix_code_actions(report.code_action.unwrap(), &uri);
+        let action = &code_actions[0];
+        assert_eq!(action.title, "Remove the debugger statement");
+        assert_eq!(action.is_preferred, Some(true));
+        let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+        assert_eq!(edits, &vec![TextEdit { range: expected_range, new_text: String::new() }]);
+    }
 }
@@ -210,8 +210,12 @@ mod test {
         InternalFormatter, LintCommandInfo, OxlintSuppressionFileAction,
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
-    use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
+    use oxc_diagnostics::{
+        GraphicalReportHandler, GraphicalTheme, NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
     use oxc_linter::{RuleTimingRecord, RuleTimingSource};
+    use oxc_span::Span;
     use rustc_hash::FxHashSet;
 
     #[test]
@@ -371,4 +375,44 @@ mod test {
             "\nFound 6 warnings and 4 errors.\nExceeded maximum number of warnings. Found 6.\n"
         );
     }
+
+    fn render_without_theme(diagnostic: OxcDiagnostic, source_text: &str) -> String {
+        let mut reporter = GraphicalReporter {
+            handler: GraphicalReportHandler::new_themed(GraphicalTheme::none()).with_links(false),
+        };
+        let error =
+            diagnostic.with_source_code(NamedSource::new("test.js", source_text.to_string()));
+        reporter.render_error(error).unwrap()
+    }
+
+    #[test]
+    fn reporter_error_single_line_span() {
+        let result = render_without_theme(
+            OxcDiagnostic::warn("`debugger` statement is not allowed")
+                .with_help("Remove the debugger statement")
+                .with_label(Span::new(18, 26)),
+            "let a = 1;\nif (a)\tdebugger;\n",
+        );
+
+        // The tab is expanded to the next tab stop, so the carets stay under `debugger`.
+        assert_eq!(
+            result,
+            "\n  ! `debugger` statement is not allowed\n   ,-[test.js:2:8]\n 1 | let a = 1;\n 2 | if (a)  debugger;\n   :         ^^^^^^^^\n   `----\n  help: Remove the debugger statement\n"
+        );
+    }
+
+    #[test]
+    fn reporter_error_multi_line_span() {
+        let result = render_without_theme(
+            OxcDiagnostic::error("Unexpected empty block")
+                .with_help("Add a comment inside the block")
+                .with_label(Span::new(7, 10)),
+            "if (a) {\n}\nfoo();\n",
+        );
+
+        assert_eq!(
+            result,
+            "\n  x Unexpected empty block\n   ,-[test.js:1:8]\n 1 | ,-> if (a) {\n 2 | `-> }\n 3 |     foo();\n   `----\n  help: Add a comment inside the block\n"
+        );
+    }
 }
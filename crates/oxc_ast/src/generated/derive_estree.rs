@@ -91,6 +91,11 @@ impl ESTree for IdentifierReference<'_> {
         state.serialize_ts_field("optional", &crate::serialize::basic::TsFalse(self));
         state.serialize_ts_field("typeAnnotation", &crate::serialize::basic::TsNull(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: None,
+            symbol_id: None,
+            reference_id: self.reference_id.get(),
+        });
         state.end();
     }
 }
@@ -104,6 +109,11 @@ impl ESTree for BindingIdentifier<'_> {
         state.serialize_ts_field("optional", &crate::serialize::basic::TsFalse(self));
         state.serialize_ts_field("typeAnnotation", &crate::serialize::basic::TsNull(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: None,
+            symbol_id: self.symbol_id.get(),
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -844,6 +854,11 @@ impl ESTree for BlockStatement<'_> {
         state.serialize_field("type", &JsonSafeString("BlockStatement"));
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -966,6 +981,11 @@ impl ESTree for ForStatement<'_> {
         state.serialize_field("update", &self.update);
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1029,6 +1049,11 @@ impl ESTree for ForInStatement<'_> {
         state.serialize_field("right", &self.right);
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1060,6 +1085,11 @@ impl ESTree for ForOfStatement<'_> {
         state.serialize_field("right", &self.right);
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1101,6 +1131,11 @@ impl ESTree for WithStatement<'_> {
         state.serialize_field("object", &self.object);
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1112,6 +1147,11 @@ impl ESTree for SwitchStatement<'_> {
         state.serialize_field("discriminant", &self.discriminant);
         state.serialize_field("cases", &self.cases);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1167,6 +1207,11 @@ impl ESTree for CatchClause<'_> {
         state.serialize_field("param", &self.param);
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1281,6 +1326,11 @@ impl ESTree for Function<'_> {
         state.serialize_field("body", &self.body);
         state.serialize_field("expression", &crate::serialize::basic::False(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1350,6 +1400,11 @@ impl ESTree for ArrowFunctionExpression<'_> {
         state.serialize_field("id", &crate::serialize::basic::Null(self));
         state.serialize_field("generator", &crate::serialize::basic::False(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1379,6 +1434,11 @@ impl ESTree for Class<'_> {
         state.serialize_ts_field("abstract", &self.r#abstract);
         state.serialize_ts_field("declare", &self.declare);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -1502,6 +1562,11 @@ impl ESTree for StaticBlock<'_> {
         state.serialize_field("type", &JsonSafeString("StaticBlock"));
         state.serialize_field("body", &self.body);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2203,6 +2268,11 @@ impl ESTree for TSEnumBody<'_> {
         state.serialize_field("type", &JsonSafeString("TSEnumBody"));
         state.serialize_field("members", &self.members);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2316,6 +2386,11 @@ impl ESTree for TSConditionalType<'_> {
         state.serialize_field("trueType", &self.true_type);
         state.serialize_field("falseType", &self.false_type);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2678,6 +2753,11 @@ impl ESTree for TSTypeAliasDeclaration<'_> {
         state.serialize_field("typeAnnotation", &self.type_annotation);
         state.serialize_field("declare", &self.declare);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2716,6 +2796,11 @@ impl ESTree for TSInterfaceDeclaration<'_> {
         state.serialize_field("body", &self.body);
         state.serialize_field("declare", &self.declare);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2783,6 +2868,11 @@ impl ESTree for TSCallSignatureDeclaration<'_> {
         );
         state.serialize_field("returnType", &self.return_type);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2812,6 +2902,11 @@ impl ESTree for TSMethodSignature<'_> {
         state.serialize_field("readonly", &crate::serialize::basic::False(self));
         state.serialize_field("static", &crate::serialize::basic::False(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2824,6 +2919,11 @@ impl ESTree for TSConstructSignatureDeclaration<'_> {
         state.serialize_field("params", &self.params);
         state.serialize_field("returnType", &self.return_type);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -2916,6 +3016,11 @@ impl ESTree for TSGlobalDeclaration<'_> {
         state.serialize_field("declare", &self.declare);
         state.serialize_field("global", &crate::serialize::basic::True(self));
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -3013,6 +3118,11 @@ impl ESTree for TSFunctionType<'_> {
         state.serialize_field("params", &crate::serialize::ts::TSFunctionTypeParams(self));
         state.serialize_field("returnType", &self.return_type);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -3026,6 +3136,11 @@ impl ESTree for TSConstructorType<'_> {
         state.serialize_field("params", &self.params);
         state.serialize_field("returnType", &self.return_type);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
@@ -3041,6 +3156,11 @@ impl ESTree for TSMappedType<'_> {
         state.serialize_field("optional", &crate::serialize::ts::TSMappedTypeOptional(self));
         state.serialize_field("readonly", &self.readonly);
         state.serialize_span(self.span);
+        state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata {
+            scope_id: self.scope_id.get(),
+            symbol_id: None,
+            reference_id: None,
+        });
         state.end();
     }
 }
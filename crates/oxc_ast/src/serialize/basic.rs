@@ -1,5 +1,6 @@
 use oxc_ast_macros::ast_meta;
-use oxc_estree::{ESTree, JsonSafeString, Serializer};
+use oxc_estree::{ESTree, JsonSafeString, Serializer, StructSerializer};
+use oxc_syntax::{reference::ReferenceId, scope::ScopeId, symbol::SymbolId};
 
 /// Serialized as `null`.
 #[ast_meta]
@@ -160,3 +161,30 @@ impl<T> ESTree for TsEmptyArray<T> {
         EmptyArray(()).serialize(serializer);
     }
 }
+
+/// Oxc-specific metadata for an AST node, serialized as the `_oxc` field.
+///
+/// Only included in output when the serializer is created with `with_oxc_metadata(true)`.
+/// IDs are only present if semantic analysis has been run on the AST.
+#[expect(clippy::struct_field_names)]
+pub struct OxcMetadata {
+    pub scope_id: Option<ScopeId>,
+    pub symbol_id: Option<SymbolId>,
+    pub reference_id: Option<ReferenceId>,
+}
+
+impl ESTree for OxcMetadata {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        if let Some(scope_id) = self.scope_id {
+            state.serialize_field("scopeId", &scope_id.index());
+        }
+        if let Some(symbol_id) = self.symbol_id {
+            state.serialize_field("symbolId", &symbol_id.index());
+        }
+        if let Some(reference_id) = self.reference_id {
+            state.serialize_field("referenceId", &reference_id.index());
+        }
+        state.end();
+    }
+}
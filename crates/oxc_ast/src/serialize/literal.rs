@@ -89,6 +89,7 @@ impl StringLiteralValue<'_, '_> {
 /// Serializer for `value` field of `BigIntLiteral`.
 ///
 /// Serialized as `null` in JSON, but updated on JS side to contain a `BigInt`.
+/// If serializer has `bigint_value_as_string` option enabled, serialized as a string instead.
 ///
 /// In the Rust type, `value` field is the BigInt as a string.
 /// This ends up in ESTree AST as `bigint` property.
@@ -104,10 +105,15 @@ impl StringLiteralValue<'_, '_> {
     ",
     raw_deser_inline
 )]
-pub struct BigIntLiteralValue<'a, 'b>(#[expect(dead_code)] pub &'b BigIntLiteral<'a>);
+pub struct BigIntLiteralValue<'a, 'b>(pub &'b BigIntLiteral<'a>);
 
 impl ESTree for BigIntLiteralValue<'_, '_> {
     fn serialize<S: Serializer>(&self, mut serializer: S) {
+        if serializer.bigint_value_as_string() {
+            JsonSafeString(self.0.value.as_str()).serialize(serializer);
+            return;
+        }
+
         // Record that this node needs fixing on JS side
         serializer.record_fix_path();
         Null(()).serialize(serializer);
@@ -2,7 +2,7 @@ use std::cmp;
 
 use oxc_ast_macros::ast_meta;
 use oxc_estree::{
-    CompactFixesSerializer, CompactSerializer, Concat2, ESTree, JsonSafeString,
+    CompactFixesSerializer, CompactSerializer, Concat2, ESTree, ESTreeComment, JsonSafeString,
     PrettyFixesSerializer, PrettySerializer, Serializer, StructSerializer,
 };
 use oxc_span::GetSpan;
@@ -77,6 +77,25 @@ impl Program<'_> {
         let serializer = PrettyFixesSerializer::with_capacity(capacity, include_ts_fields, ranges);
         serializer.serialize_with_fixes(self)
     }
+
+    /// Get comments, for attaching to AST nodes with `ESTreeSerializer::with_attached_comments`.
+    ///
+    /// ```ignore
+    /// let mut serializer = CompactSerializer::new(include_ts_fields, ranges)
+    ///     .with_attached_comments(program, program.estree_comments());
+    /// program.serialize(&mut serializer);
+    /// ```
+    pub fn estree_comments(&self) -> Vec<ESTreeComment> {
+        self.comments
+            .iter()
+            .map(|comment| ESTreeComment {
+                is_block: comment.is_block(),
+                value: comment.content_span().source_text(self.source_text).to_string(),
+                start: comment.span.start,
+                end: comment.span.end,
+            })
+            .collect()
+    }
 }
 
 /// Serializer for `Program`.
@@ -229,3 +248,74 @@ impl ESTree for CommentValue<'_> {
         unimplemented!();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use oxc_allocator::Allocator;
+    use oxc_span::SPAN;
+    use oxc_syntax::{reference::ReferenceId, symbol::SymbolId};
+
+    use super::*;
+    use crate::builder::AstBuilder;
+
+    fn to_json<T: ESTree>(node: &T, oxc_metadata: bool) -> String {
+        let mut serializer = CompactSerializer::new(false, false).with_oxc_metadata(oxc_metadata);
+        node.serialize(&mut serializer);
+        serializer.into_string()
+    }
+
+    #[test]
+    fn oxc_metadata() {
+        let allocator = Allocator::default();
+        let builder = AstBuilder::new(&allocator);
+
+        let binding = BindingIdentifier::new_with_symbol_id(SPAN, "x", SymbolId::new(1), &builder);
+        assert_eq!(
+            to_json(&binding, true),
+            r#"{"type":"Identifier","name":"x","start":0,"end":0,"_oxc":{"symbolId":1}}"#
+        );
+        assert_eq!(
+            to_json(&binding, false),
+            r#"{"type":"Identifier","name":"x","start":0,"end":0}"#
+        );
+
+        let reference =
+            IdentifierReference::new_with_reference_id(SPAN, "x", ReferenceId::new(2), &builder);
+        assert_eq!(
+            to_json(&reference, true),
+            r#"{"type":"Identifier","name":"x","start":0,"end":0,"_oxc":{"referenceId":2}}"#
+        );
+
+        // Semantic analysis has not been run, so no IDs
+        let reference = IdentifierReference::new(SPAN, "x", &builder);
+        assert_eq!(
+            to_json(&reference, true),
+            r#"{"type":"Identifier","name":"x","start":0,"end":0,"_oxc":{}}"#
+        );
+    }
+
+    #[test]
+    fn bigint_value_as_string() {
+        let bigint = BigIntLiteral {
+            node_id: Cell::default(),
+            span: SPAN,
+            value: "123456789012345678901234567890".into(),
+            raw: None,
+            base: BigintBase::Decimal,
+        };
+
+        let mut serializer = CompactSerializer::new(false, false).with_bigint_value_as_string(true);
+        bigint.serialize(&mut serializer);
+        assert_eq!(
+            serializer.into_string(),
+            r#"{"type":"Literal","value":"123456789012345678901234567890","raw":null,"bigint":"123456789012345678901234567890","start":0,"end":0}"#
+        );
+
+        assert_eq!(
+            to_json(&bigint, false),
+            r#"{"type":"Literal","value":null,"raw":null,"bigint":"123456789012345678901234567890","start":0,"end":0}"#
+        );
+    }
+}
@@ -684,6 +684,33 @@ impl CodeBuffer {
         }
     }
 
+    /// Shorten the buffer to `len` bytes.
+    ///
+    /// Has no effect if `len` is greater than or equal to current length of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not on a UTF-8 character boundary.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::code_buffer::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("foobar");
+    /// code.truncate(3);
+    /// assert_eq!(code.as_str(), "foo");
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.buf.len() {
+            assert!(
+                self.as_str().is_char_boundary(len),
+                "`len` is not on a UTF-8 character boundary"
+            );
+            self.buf.truncate(len);
+        }
+    }
+
     /// Get contents of buffer as a byte slice.
     ///
     /// # Example
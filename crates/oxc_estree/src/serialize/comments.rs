@@ -0,0 +1,276 @@
+//! Attachment of comments to AST nodes, as `leadingComments`, `trailingComments` and `innerComments`
+//! fields, following Babel's rules.
+//!
+//! Attachment is done in 2 passes:
+//!
+//! 1. AST is serialized with a [`CommentRecorder`], which discards the JSON output, and only records
+//!    the spans of nodes and which nodes are children of which. When each node is finished, comments
+//!    which are directly inside it (not inside any of its children) are attached to its children,
+//!    or to the node itself if it has no children.
+//! 2. AST is serialized again with [`AttachedComments`] produced by pass 1. When each node is finished,
+//!    the comments attached to it are added to the node as extra fields.
+//!
+//! Nodes are identified by their post-order index, which is the same in both passes.
+//! Only structs which call [`StructSerializer::serialize_span`] are considered nodes.
+//!
+//! A comment is attached as:
+//! * `trailingComments` of the closest preceding sibling node, and
+//! * `leadingComments` of the closest following sibling node.
+//!
+//! If there is no sibling on either side, the comment is attached to its containing node as `innerComments`.
+//! So e.g. a comment between 2 statements is a trailing comment of the first statement,
+//! and a leading comment of the second.
+//!
+//! Babel's special cases for comments in lists (e.g. `foo(/* comment */)` is an inner comment
+//! of the `CallExpression` in Babel, but here it's a trailing comment of `foo`) are not replicated.
+//!
+//! [`StructSerializer::serialize_span`]: super::StructSerializer::serialize_span
+
+use super::{ESTree, JsonSafeString, SequenceSerializer, Serializer, StructSerializer};
+
+/// A comment to attach to AST nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ESTreeComment {
+    /// `true` for `/* ... */` comments, `false` for `// ...` comments.
+    pub is_block: bool,
+    /// Content of comment, excluding `//`, `/*` and `*/`.
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl ESTree for ESTreeComment {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        let kind = if self.is_block { "CommentBlock" } else { "CommentLine" };
+        state.serialize_field("type", &JsonSafeString(kind));
+        state.serialize_field("value", &self.value);
+        // Don't use `serialize_span`, as that would make the comment count as a node
+        state.serialize_field("start", &self.start);
+        state.serialize_field("end", &self.end);
+        if state.ranges() {
+            state.serialize_field("range", &[self.start, self.end]);
+        }
+        state.end();
+    }
+}
+
+/// Position of an attached comment relative to the node it's attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CommentPosition {
+    Leading,
+    Inner,
+    Trailing,
+}
+
+impl CommentPosition {
+    fn field_name(self) -> &'static str {
+        match self {
+            Self::Leading => "leadingComments",
+            Self::Inner => "innerComments",
+            Self::Trailing => "trailingComments",
+        }
+    }
+}
+
+/// State of comment attachment in a serializer.
+pub(super) enum CommentAttachment {
+    /// Pass 1: Recording node structure.
+    Recording(CommentRecorder),
+    /// Pass 2: Outputting attached comments.
+    Attaching(AttachedComments),
+}
+
+/// Node which has finished serializing.
+#[derive(Clone, Copy)]
+struct ChildNode {
+    index: u32,
+    start: u32,
+    end: u32,
+}
+
+/// A struct which is being serialized.
+#[derive(Default)]
+struct Frame {
+    /// Span of the struct, if it has one. Structs without a span are not nodes.
+    span: Option<[u32; 2]>,
+    /// Nodes directly inside this struct.
+    children: Vec<ChildNode>,
+}
+
+/// Pass 1 state.
+pub(super) struct CommentRecorder {
+    /// Comments, sorted by `start`.
+    comments: Vec<ESTreeComment>,
+    /// `true` for comments which have already been attached.
+    attached: Vec<bool>,
+    /// Stack of structs being serialized. First entry is a dummy root.
+    frames: Vec<Frame>,
+    next_node_index: u32,
+    /// Attachments, as `(node index, position, comment index)`.
+    attachments: Vec<(u32, CommentPosition, u32)>,
+}
+
+impl CommentRecorder {
+    pub(super) fn new(mut comments: Vec<ESTreeComment>) -> Self {
+        comments.sort_unstable_by_key(|comment| comment.start);
+        let attached = vec![false; comments.len()];
+        Self {
+            comments,
+            attached,
+            frames: vec![Frame::default()],
+            next_node_index: 0,
+            attachments: vec![],
+        }
+    }
+
+    /// Called when starting to serialize a struct.
+    pub(super) fn enter_struct(&mut self) {
+        self.frames.push(Frame::default());
+    }
+
+    /// Called when a struct's span is serialized.
+    pub(super) fn record_span(&mut self, range: [u32; 2]) {
+        let frame = self.frames.last_mut().unwrap();
+        // If a struct is flattened into another, the outer struct's span takes precedence
+        if frame.span.is_none() {
+            frame.span = Some(range);
+        }
+    }
+
+    /// Called when finished serializing a struct.
+    pub(super) fn exit_struct(&mut self) {
+        debug_assert!(self.frames.len() > 1);
+        let frame = self.frames.pop().unwrap();
+        let Some([start, end]) = frame.span else {
+            // Not a node. Its child nodes are children of the parent node.
+            self.frames.last_mut().unwrap().children.extend(frame.children);
+            return;
+        };
+
+        let index = self.next_node_index;
+        self.next_node_index += 1;
+
+        self.attach_comments_in(start, end, Some(index), frame.children);
+        self.frames.last_mut().unwrap().children.push(ChildNode { index, start, end });
+    }
+
+    /// Attach comments between `start` and `end` which are not inside any of `children`.
+    ///
+    /// Comments with no sibling on either side are attached to `container` as inner comments.
+    fn attach_comments_in(
+        &mut self,
+        start: u32,
+        end: u32,
+        container: Option<u32>,
+        mut children: Vec<ChildNode>,
+    ) {
+        // Children are usually serialized in source order, but not always
+        children.sort_unstable_by_key(|child| child.start);
+
+        let first = self.comments.partition_point(|comment| comment.start < start);
+        for comment_index in first..self.comments.len() {
+            let comment = &self.comments[comment_index];
+            if comment.start >= end {
+                break;
+            }
+            // Comments inside children have already been attached when the child was finished
+            if self.attached[comment_index] || comment.end > end {
+                continue;
+            }
+
+            let next = children.partition_point(|child| child.start < comment.end);
+            let following = children.get(next);
+            let preceding = next
+                .checked_sub(1)
+                .map(|prev| &children[prev])
+                .filter(|child| child.end <= comment.start);
+
+            #[expect(clippy::cast_possible_truncation)]
+            let comment_index_u32 = comment_index as u32;
+            if preceding.is_none() && following.is_none() {
+                let Some(container) = container else { continue };
+                self.attachments.push((container, CommentPosition::Inner, comment_index_u32));
+            } else {
+                if let Some(preceding) = preceding {
+                    self.attachments.push((
+                        preceding.index,
+                        CommentPosition::Trailing,
+                        comment_index_u32,
+                    ));
+                }
+                if let Some(following) = following {
+                    self.attachments.push((
+                        following.index,
+                        CommentPosition::Leading,
+                        comment_index_u32,
+                    ));
+                }
+            }
+            self.attached[comment_index] = true;
+        }
+    }
+
+    /// Finish recording, and attach any remaining comments which are outside all nodes
+    /// (e.g. comments before start of `Program` in TS-ESTree AST) to the top-level nodes.
+    pub(super) fn finish(mut self) -> AttachedComments {
+        debug_assert_eq!(self.frames.len(), 1);
+        let root = self.frames.pop().unwrap();
+        self.attach_comments_in(0, u32::MAX, None, root.children);
+
+        self.attachments.sort_unstable();
+        AttachedComments {
+            comments: self.comments,
+            attachments: self.attachments,
+            next_node_index: 0,
+        }
+    }
+}
+
+/// Pass 2 state.
+pub(super) struct AttachedComments {
+    comments: Vec<ESTreeComment>,
+    /// Attachments, as `(node index, position, comment index)`, sorted.
+    attachments: Vec<(u32, CommentPosition, u32)>,
+    next_node_index: u32,
+}
+
+impl AttachedComments {
+    /// Called when finished serializing a node. Adds fields for comments attached to the node.
+    pub(super) fn serialize_node_comments<S: StructSerializer>(&mut self, state: &mut S) {
+        let index = self.next_node_index;
+        self.next_node_index += 1;
+
+        let first = self.attachments.partition_point(|&(node_index, ..)| node_index < index);
+        let len =
+            self.attachments[first..].partition_point(|&(node_index, ..)| node_index == index);
+        let mut attachments = &self.attachments[first..first + len];
+
+        while let Some(&(_, position, _)) = attachments.first() {
+            let count = attachments.partition_point(|&(_, pos, _)| pos == position);
+            let (same_position, rest) = attachments.split_at(count);
+            state.serialize_field(
+                position.field_name(),
+                &NodeComments { comments: &self.comments, attachments: same_position },
+            );
+            attachments = rest;
+        }
+    }
+}
+
+/// Serializer for the comments in one of a node's `leadingComments`, `trailingComments`,
+/// or `innerComments` fields.
+struct NodeComments<'c> {
+    comments: &'c [ESTreeComment],
+    attachments: &'c [(u32, CommentPosition, u32)],
+}
+
+impl ESTree for NodeComments<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut seq = serializer.serialize_sequence();
+        for &(_, _, comment_index) in self.attachments {
+            seq.serialize_element(&self.comments[comment_index as usize]);
+        }
+        seq.end();
+    }
+}
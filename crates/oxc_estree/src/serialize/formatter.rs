@@ -22,6 +22,13 @@ pub trait Formatter {
     /// Called after the last element of a sequence / last element of a struct.
     /// If the struct/sequence has no fields/elements, this is not called.
     fn after_last_element(&mut self, buffer: &mut CodeBuffer);
+
+    /// Called when the first field of a struct has been removed from the buffer after it was written,
+    /// to reverse any change to formatter's state made by `before_first_element`.
+    ///
+    /// Default implementation does nothing, which is correct for formatters which are stateless.
+    #[inline(always)]
+    fn undo_first_element(&mut self) {}
 }
 
 /// Compact formatter.
@@ -100,6 +107,10 @@ impl Formatter for PrettyFormatter {
         self.indent -= 1;
         self.push_new_line_and_indent(buffer);
     }
+
+    fn undo_first_element(&mut self) {
+        self.indent -= 1;
+    }
 }
 
 impl PrettyFormatter {
@@ -0,0 +1,148 @@
+use super::{ESTree, Serializer, StructSerializer};
+
+/// Unit in which columns of `loc` fields are measured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnUnit {
+    /// UTF-16 code units, as used by JS strings (e.g. ESLint, Acorn).
+    #[default]
+    Utf16,
+    /// UTF-8 bytes.
+    Utf8,
+    /// Unicode scalar values.
+    Chars,
+}
+
+/// Table of line start offsets, used to convert offsets to line and column for `loc` fields.
+pub(super) struct LineTable {
+    /// UTF-8 offset of start of each line. First entry is always 0.
+    line_starts: Vec<u32>,
+    /// Offsets where difference between UTF-8 bytes and `column_unit`s changes, in ascending order.
+    /// Empty if `column_unit` is UTF-8, or source text is entirely ASCII.
+    translations: Vec<Translation>,
+    column_base: u32,
+}
+
+/// Entry in [`LineTable`]'s conversion table.
+struct Translation {
+    /// UTF-8 offset of the end of a non-ASCII character.
+    utf8_offset: u32,
+    /// Number of UTF-8 bytes in excess of `column_unit`s in source text before `utf8_offset`.
+    difference: u32,
+}
+
+impl LineTable {
+    #[expect(clippy::cast_possible_truncation)]
+    pub(super) fn new(source_text: &str, column_base: u32, column_unit: ColumnUnit) -> Self {
+        let mut line_starts = vec![0];
+        let mut translations = vec![];
+        let mut difference = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            match ch {
+                '\r' if chars.peek().is_some_and(|&(_, next)| next == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                    line_starts.push((offset + ch.len_utf8()) as u32);
+                }
+                _ => {}
+            }
+
+            if !ch.is_ascii() {
+                let units = match column_unit {
+                    ColumnUnit::Utf16 => ch.len_utf16(),
+                    ColumnUnit::Utf8 => continue,
+                    ColumnUnit::Chars => 1,
+                };
+                difference += (ch.len_utf8() - units) as u32;
+                let utf8_offset = (offset + ch.len_utf8()) as u32;
+                translations.push(Translation { utf8_offset, difference });
+            }
+        }
+
+        Self { line_starts, translations, column_base }
+    }
+
+    /// Get number of UTF-8 bytes in excess of `column_unit`s in source text before UTF-8 `offset`.
+    fn difference(&self, offset: u32) -> u32 {
+        let index =
+            self.translations.partition_point(|translation| translation.utf8_offset <= offset);
+        if index == 0 { 0 } else { self.translations[index - 1].difference }
+    }
+
+    /// Get position of UTF-8 `offset` in source text.
+    #[expect(clippy::cast_possible_truncation)]
+    fn position(&self, offset: u32) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let mut column = offset - line_start;
+        if !self.translations.is_empty() {
+            column -= self.difference(offset) - self.difference(line_start);
+        }
+        Position { line: line as u32, column: column + self.column_base }
+    }
+
+    /// Get `loc` for a node with `range`.
+    pub(super) fn loc(&self, range: [u32; 2]) -> SourceLocation {
+        SourceLocation { start: self.position(range[0]), end: self.position(range[1]) }
+    }
+}
+
+/// `loc` field of an AST node.
+pub(super) struct SourceLocation {
+    start: Position,
+    end: Position,
+}
+
+impl ESTree for SourceLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("start", &self.start);
+        state.serialize_field("end", &self.end);
+        state.end();
+    }
+}
+
+/// Position within source text. `line` is 1-based.
+struct Position {
+    line: u32,
+    column: u32,
+}
+
+impl ESTree for Position {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("line", &self.line);
+        state.serialize_field("column", &self.column);
+        state.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_table_positions() {
+        let table = LineTable::new("a\r\nb\rc\u{2028}d\n\né😀x", 0, ColumnUnit::Utf16);
+        let pos = |offset| {
+            let Position { line, column } = table.position(offset);
+            (line, column)
+        };
+        assert_eq!(pos(0), (1, 0));
+        assert_eq!(pos(1), (1, 1));
+        assert_eq!(pos(3), (2, 0));
+        assert_eq!(pos(5), (3, 0));
+        assert_eq!(pos(9), (4, 0));
+        assert_eq!(pos(11), (5, 0));
+        // `x` after `é😀`
+        assert_eq!(pos(18), (6, 3));
+
+        // Non-ASCII characters on previous lines don't affect column
+        let table = LineTable::new("😀é\n😀x", 0, ColumnUnit::Utf16);
+        assert_eq!(table.position(11).column, 2);
+
+        let table = LineTable::new("é😀x", 1, ColumnUnit::Utf8);
+        assert_eq!(table.position(6).column, 7);
+        let table = LineTable::new("é😀x", 1, ColumnUnit::Chars);
+        assert_eq!(table.position(6).column, 3);
+    }
+}
@@ -9,19 +9,25 @@ use oxc_data_structures::{
 };
 
 mod blanket;
+mod comments;
 mod concat;
 mod config;
 mod formatter;
+mod loc;
 mod primitives;
 mod sequences;
 mod strings;
 mod structs;
+use comments::{CommentAttachment, CommentRecorder};
+use loc::LineTable;
 use sequences::ESTreeSequenceSerializer;
 use structs::ESTreeStructSerializer;
 
+pub use comments::ESTreeComment;
 pub use concat::{Concat2, Concat3, ConcatElement};
 pub use config::{Config, ConfigFixes, ConfigNoFixes};
 pub use formatter::{CompactFormatter, Formatter, PrettyFormatter};
+pub use loc::ColumnUnit;
 pub use sequences::SequenceSerializer;
 pub use strings::{JsonSafeString, LoneSurrogatesString};
 pub use structs::{ESTreeSpan, FlatStructSerializer, StructSerializer};
@@ -31,6 +37,18 @@ pub trait ESTree {
     fn serialize<S: Serializer>(&self, serializer: S);
 }
 
+/// Serialize a single AST node, and all its descendants, with `serializer`.
+///
+/// `node` does not need to be the root of the AST. e.g. it can be a single function
+/// for displaying in an editor's hover or selection UI.
+///
+/// Spans in the output are always absolute offsets in the source text which the AST was parsed from,
+/// not relative to the start of `node`.
+#[inline(always)]
+pub fn serialize_node<T: ESTree + ?Sized, S: Serializer>(node: &T, serializer: S) {
+    node.serialize(serializer);
+}
+
 /// Trait for serializers.
 pub trait Serializer {
     /// `true` if serializer's formatter produces compact JSON (not pretty-printed JSON).
@@ -50,6 +68,12 @@ pub trait Serializer {
     /// Get whether output should contain `range` fields.
     fn ranges(&self) -> bool;
 
+    /// Get whether output should contain `_oxc` metadata fields.
+    fn oxc_metadata(&self) -> bool;
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    fn bigint_value_as_string(&self) -> bool;
+
     /// Serialize struct.
     fn serialize_struct(self) -> Self::StructSerializer;
 
@@ -89,6 +113,13 @@ pub struct ESTreeSerializer<C: Config, F: Formatter> {
     trace_path: NonEmptyStack<TracePathPart>,
     fixes_buffer: CodeBuffer,
     config: C,
+    oxc_metadata: bool,
+    bigint_value_as_string: bool,
+    omit_null_fields: bool,
+    comments: Option<Box<CommentAttachment>>,
+    line_table: Option<Box<LineTable>>,
+    max_inline_array_len: usize,
+    field_renames: Box<[(&'static str, &'static str, &'static str)]>,
 }
 
 impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
@@ -100,6 +131,13 @@ impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
             trace_path: NonEmptyStack::new(TracePathPart::Index(0)),
             fixes_buffer: CodeBuffer::new(),
             config: C::new(include_ts_fields, ranges),
+            oxc_metadata: false,
+            bigint_value_as_string: false,
+            omit_null_fields: false,
+            comments: None,
+            line_table: None,
+            max_inline_array_len: 0,
+            field_renames: Box::new([]),
         }
     }
 
@@ -111,9 +149,117 @@ impl<C: Config, F: Formatter> ESTreeSerializer<C, F> {
             trace_path: NonEmptyStack::new(TracePathPart::Index(0)),
             fixes_buffer: CodeBuffer::new(),
             config: C::new(include_ts_fields, ranges),
+            oxc_metadata: false,
+            bigint_value_as_string: false,
+            omit_null_fields: false,
+            comments: None,
+            line_table: None,
+            max_inline_array_len: 0,
+            field_renames: Box::new([]),
         }
     }
 
+    /// Include an `_oxc` field on AST nodes, containing Oxc-specific metadata
+    /// (e.g. `scopeId`, `symbolId`, `referenceId` from semantic analysis).
+    ///
+    /// Disabled by default, so output is pure ESTree.
+    #[must_use]
+    pub fn with_oxc_metadata(mut self, oxc_metadata: bool) -> Self {
+        self.oxc_metadata = oxc_metadata;
+        self
+    }
+
+    /// Output `value` field of `BigIntLiteral`s as a decimal string (e.g. `"value": "123"`),
+    /// instead of `null`.
+    ///
+    /// JSON has no `BigInt` type, so by default `value` is `null`, and is set on JS side
+    /// (using fix paths, if enabled). This option is for consumers which read the JSON directly.
+    #[must_use]
+    pub fn with_bigint_value_as_string(mut self, bigint_value_as_string: bool) -> Self {
+        self.bigint_value_as_string = bigint_value_as_string;
+        self
+    }
+
+    /// Omit struct fields whose value is `null` (e.g. `"optional": null` or `"typeAnnotation": null`),
+    /// to reduce size of the JSON.
+    ///
+    /// Consumers must treat a missing field the same as `null`. Elements of arrays are not affected.
+    #[must_use]
+    pub fn with_omit_null_fields(mut self, omit_null_fields: bool) -> Self {
+        self.omit_null_fields = omit_null_fields;
+        self
+    }
+
+    /// Include a `loc` field on AST nodes, containing `line` and `column` of the node's start and end,
+    /// e.g. `"loc": {"start": {"line": 1, "column": 0}, "end": {"line": 1, "column": 5}}`.
+    ///
+    /// Spans of the AST must be UTF-8 offsets into `source_text`. Lines are 1-based.
+    /// Columns start at `column_base` (0 for ESLint, 1 for some other tools) and are measured
+    /// in `column_unit`s.
+    #[must_use]
+    pub fn with_loc(
+        mut self,
+        source_text: &str,
+        column_base: u32,
+        column_unit: ColumnUnit,
+    ) -> Self {
+        self.line_table = Some(Box::new(LineTable::new(source_text, column_base, column_unit)));
+        self
+    }
+
+    /// Print arrays containing `max_len` or fewer elements on a single line (e.g. `"range": [0, 5]`),
+    /// if all their elements are primitives (not objects or arrays).
+    ///
+    /// Only affects pretty formatters. Disabled by default (`max_len` of 0).
+    #[must_use]
+    pub fn with_max_inline_array_len(mut self, max_len: usize) -> Self {
+        self.max_inline_array_len = max_len;
+        self
+    }
+
+    /// Output fields of AST nodes under different names.
+    ///
+    /// Each entry of `renames` is `(node_type, field, new_name)`. e.g. `("Program", "body", "statements")`
+    /// outputs `body` field of `Program` nodes as `statements`. Fields of other node types are unaffected.
+    ///
+    /// `new_name` must not contain any characters which require escaping in JSON.
+    #[must_use]
+    pub fn with_field_renames(
+        mut self,
+        renames: impl IntoIterator<Item = (&'static str, &'static str, &'static str)>,
+    ) -> Self {
+        self.field_renames = renames.into_iter().collect();
+        self
+    }
+
+    /// Attach `comments` to the nodes of `node`'s AST as `leadingComments`, `trailingComments`
+    /// and `innerComments` fields, following Babel's rules, when `node` is serialized with this serializer.
+    ///
+    /// A comment is attached as a trailing comment of the closest preceding sibling node,
+    /// and as a leading comment of the closest following sibling node. If it has no siblings,
+    /// it's attached to the node containing it as an inner comment.
+    ///
+    /// This serializes `node` an extra time to determine the positions of nodes, so is slower.
+    #[must_use]
+    pub fn with_attached_comments<T: ESTree + ?Sized>(
+        mut self,
+        node: &T,
+        comments: Vec<ESTreeComment>,
+    ) -> Self {
+        let mut recorder =
+            CompactSerializer::new(self.config.include_ts_fields(), self.config.ranges())
+                .with_oxc_metadata(self.oxc_metadata);
+        recorder.comments =
+            Some(Box::new(CommentAttachment::Recording(CommentRecorder::new(comments))));
+        node.serialize(&mut recorder);
+
+        let Some(CommentAttachment::Recording(recorder)) = recorder.comments.map(|c| *c) else {
+            unreachable!();
+        };
+        self.comments = Some(Box::new(CommentAttachment::Attaching(recorder.finish())));
+        self
+    }
+
     /// Serialize `node` and output a `JSON` string containing
     /// `{ "node": { ... }, "fixes": [ ... ]}`, where `node` is the serialized AST node,
     /// and `fixes` is a list of paths to any `Literal`s which are `BigInt`s or `RegExp`s.
@@ -181,6 +327,18 @@ impl<'s, C: Config, F: Formatter> Serializer for &'s mut ESTreeSerializer<C, F>
         self.config.ranges()
     }
 
+    /// Get whether output should contain `_oxc` metadata fields.
+    #[inline(always)]
+    fn oxc_metadata(&self) -> bool {
+        self.oxc_metadata
+    }
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    #[inline(always)]
+    fn bigint_value_as_string(&self) -> bool {
+        self.bigint_value_as_string
+    }
+
     /// Serialize struct.
     #[inline(always)]
     fn serialize_struct(self) -> ESTreeStructSerializer<'s, C, F> {
@@ -250,3 +408,126 @@ pub enum TracePathPart {
 impl TracePathPart {
     pub const DUMMY: Self = TracePathPart::Index(0);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_with_capacity() {
+        struct Foo<'a> {
+            name: &'a str,
+            values: &'a [f64],
+            nested: Option<&'a Foo<'a>>,
+        }
+
+        impl ESTree for Foo<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_field("name", &self.name);
+                state.serialize_field("values", &self.values);
+                state.serialize_field("nested", &self.nested);
+                state.end();
+            }
+        }
+
+        fn serialize<C: Config, F: Formatter>(
+            foo: &Foo,
+            mut serializer: ESTreeSerializer<C, F>,
+        ) -> String {
+            foo.serialize(&mut serializer);
+            serializer.into_string()
+        }
+
+        let inner = Foo { name: "inner", values: &[], nested: None };
+        let foo = Foo { name: "outer", values: &[1.0, 2.5, -3.0], nested: Some(&inner) };
+
+        // Capacity is only a hint, so output must be identical whether the buffer
+        // is under-sized, exactly sized, or over-sized
+        let expected = serialize(&foo, CompactSerializer::new(true, false));
+        for capacity in [0, 1, expected.len(), expected.len() * 16] {
+            let s = serialize(&foo, CompactSerializer::with_capacity(capacity, true, false));
+            assert_eq!(s, expected);
+        }
+
+        let expected = serialize(&foo, PrettySerializer::new(true, false));
+        for capacity in [0, 1, expected.len(), expected.len() * 80] {
+            let s = serialize(&foo, PrettySerializer::with_capacity(capacity, true, false));
+            assert_eq!(s, expected);
+        }
+    }
+
+    #[test]
+    fn attach_comments() {
+        #[derive(Clone, Copy)]
+        struct TestSpan(u32, u32);
+
+        impl ESTreeSpan for TestSpan {
+            fn range(self) -> [u32; 2] {
+                [self.0, self.1]
+            }
+        }
+
+        struct Node<'a> {
+            kind: &'a str,
+            span: TestSpan,
+            body: &'a [Node<'a>],
+        }
+
+        impl ESTree for Node<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_field("type", &JsonSafeString(self.kind));
+                state.serialize_field("body", &self.body);
+                // Span after body, like `Program`
+                state.serialize_span(self.span);
+                state.end();
+            }
+        }
+
+        fn comment(is_block: bool, value: &str, start: u32, end: u32) -> ESTreeComment {
+            ESTreeComment { is_block, value: value.to_string(), start, end }
+        }
+
+        // `/*a*/x;/*b*/y;{/*c*/}//d`
+        let program = Node {
+            kind: "Program",
+            span: TestSpan(0, 24),
+            body: &[
+                Node { kind: "X", span: TestSpan(5, 7), body: &[] },
+                Node { kind: "Y", span: TestSpan(12, 14), body: &[] },
+                Node { kind: "Block", span: TestSpan(14, 21), body: &[] },
+            ],
+        };
+        let comments = vec![
+            comment(false, "d", 21, 24),
+            comment(true, "a", 0, 5),
+            comment(true, "b", 7, 12),
+            comment(true, "c", 15, 20),
+        ];
+
+        let mut serializer =
+            CompactSerializer::new(false, false).with_attached_comments(&program, comments);
+        program.serialize(&mut serializer);
+        assert_eq!(
+            serializer.into_string(),
+            concat!(
+                r#"{"type":"Program","body":["#,
+                r#"{"type":"X","body":[],"start":5,"end":7,"#,
+                r#""leadingComments":[{"type":"CommentBlock","value":"a","start":0,"end":5}],"#,
+                r#""trailingComments":[{"type":"CommentBlock","value":"b","start":7,"end":12}]},"#,
+                r#"{"type":"Y","body":[],"start":12,"end":14,"#,
+                r#""leadingComments":[{"type":"CommentBlock","value":"b","start":7,"end":12}]},"#,
+                r#"{"type":"Block","body":[],"start":14,"end":21,"#,
+                r#""innerComments":[{"type":"CommentBlock","value":"c","start":15,"end":20}],"#,
+                r#""trailingComments":[{"type":"CommentLine","value":"d","start":21,"end":24}]}"#,
+                r#"],"start":0,"end":24}"#,
+            )
+        );
+
+        // Without comments attached, output is unchanged
+        let mut serializer = CompactSerializer::new(false, false);
+        program.serialize(&mut serializer);
+        assert!(!serializer.into_string().contains("Comments"));
+    }
+}
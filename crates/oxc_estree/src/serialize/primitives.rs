@@ -23,6 +23,8 @@ impl ESTree for f64 {
             serializer.buffer_mut().print_str("null");
         } else if *self == f64::INFINITY {
             // Serialize `INFINITY` as `1e+400. `JSON.parse` deserializes this as `Infinity`.
+            // This matches Acorn, which gives `value: Infinity` for out-of-range literals like `1e999`,
+            // so don't serialize it as `null`.
             serializer.buffer_mut().print_str("1e+400");
         } else {
             // Serialize `-INFINITY` as `-1e+400`. `JSON.parse` deserializes this as `-Infinity`.
@@ -91,6 +93,15 @@ mod tests {
             (123_456.0, "123456"),
             (0.12345, "0.12345"),
             (123.45, "123.45"),
+            (0.1, "0.1"),
+            (0.1 + 0.2, "0.30000000000000004"),
+            (123_456_789.123, "123456789.123"),
+            (1e20, "100000000000000000000"),
+            (1e21, "1e+21"),
+            (1e-6, "0.000001"),
+            (1e-7, "1e-7"),
+            (5e-324, "5e-324"),
+            (-0.0, "0"),
             (f64::MIN, "-1.7976931348623157e+308"),
             (f64::MAX, "1.7976931348623157e+308"),
             (f64::INFINITY, "1e+400"),
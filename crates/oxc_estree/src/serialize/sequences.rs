@@ -17,6 +17,10 @@ pub struct ESTreeSequenceSerializer<'s, C: Config, F: Formatter> {
     serializer: &'s mut ESTreeSerializer<C, F>,
     /// Length of sequence
     len: usize,
+    /// Position in buffer after opening `[`
+    start: usize,
+    /// `true` if all elements so far are primitives (not structs or sequences)
+    all_primitive: bool,
 }
 
 impl<'s, C: Config, F: Formatter> ESTreeSequenceSerializer<'s, C, F> {
@@ -28,9 +32,11 @@ impl<'s, C: Config, F: Formatter> ESTreeSequenceSerializer<'s, C, F> {
             serializer.trace_path.push(TracePathPart::DUMMY);
         }
 
-        serializer.buffer_mut().print_ascii_byte(b'[');
+        let buffer = serializer.buffer_mut();
+        buffer.print_ascii_byte(b'[');
+        let start = buffer.len();
 
-        Self { serializer, len: 0 }
+        Self { serializer, len: 0, start, all_primitive: true }
     }
 }
 
@@ -50,8 +56,14 @@ impl<C: Config, F: Formatter> SequenceSerializer for ESTreeSequenceSerializer<'_
             formatter.before_later_element(buffer);
         }
 
+        let element_start = buffer.len();
         value.serialize(&mut *self.serializer);
         self.len += 1;
+
+        if !F::IS_COMPACT && self.all_primitive {
+            let first_byte = self.serializer.buffer.as_bytes().get(element_start);
+            self.all_primitive = !matches!(first_byte, Some(b'{' | b'['));
+        }
     }
 
     /// Finish serializing sequence.
@@ -64,9 +76,23 @@ impl<C: Config, F: Formatter> SequenceSerializer for ESTreeSequenceSerializer<'_
             unsafe { self.serializer.trace_path.pop_unchecked() };
         }
 
+        let max_inline_len = self.serializer.max_inline_array_len;
         let (buffer, formatter) = self.serializer.buffer_and_formatter_mut();
         if self.len > 0 {
             formatter.after_last_element(buffer);
+
+            if !F::IS_COMPACT && self.all_primitive && self.len <= max_inline_len {
+                // Re-print elements on a single line.
+                // Primitives never contain line breaks, so each element is on its own line.
+                let inline = buffer.as_str()[self.start..]
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                buffer.truncate(self.start);
+                buffer.print_str(&inline);
+            }
         }
         buffer.print_ascii_byte(b']');
     }
@@ -141,4 +167,57 @@ mod tests {
 }"#
         );
     }
+
+    #[test]
+    fn serialize_small_sequence_inline() {
+        struct Node<'a> {
+            range: [u32; 2],
+            body: &'a [u32],
+            children: &'a [Node<'a>],
+        }
+
+        impl ESTree for Node<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_field("range", &self.range);
+                state.serialize_field("body", &self.body);
+                state.serialize_field("children", &self.children);
+                state.end();
+            }
+        }
+
+        let child = Node { range: [1, 2], body: &[], children: &[] };
+        let node = Node { range: [0, 5], body: &[1, 2, 3], children: &[child] };
+
+        let mut serializer = PrettySerializer::default().with_max_inline_array_len(2);
+        node.serialize(&mut serializer);
+        let s = serializer.into_string();
+        assert_eq!(
+            &s,
+            r#"{
+  "range": [0, 5],
+  "body": [
+    1,
+    2,
+    3
+  ],
+  "children": [
+    {
+      "range": [1, 2],
+      "body": [],
+      "children": []
+    }
+  ]
+}"#
+        );
+
+        // Compact output is unaffected
+        let mut serializer = CompactSerializer::default().with_max_inline_array_len(2);
+        node.serialize(&mut serializer);
+        let s = serializer.into_string();
+        assert_eq!(
+            &s,
+            r#"{"range":[0,5],"body":[1,2,3],"children":[{"range":[1,2],"body":[],"children":[]}]}"#
+        );
+    }
 }
@@ -1,8 +1,8 @@
 use oxc_data_structures::code_buffer::CodeBuffer;
 
 use super::{
-    Config, ESTree, ESTreeSequenceSerializer, ESTreeSerializer, Formatter, Serializer,
-    TracePathPart,
+    CommentAttachment, Config, ESTree, ESTreeSequenceSerializer, ESTreeSerializer, Formatter,
+    Serializer, TracePathPart,
 };
 
 /// Trait for struct serializers.
@@ -43,6 +43,13 @@ pub trait StructSerializer {
     /// * Otherwise, outputs only `start` and `end`.
     fn serialize_span<S: ESTreeSpan>(&mut self, span: S);
 
+    /// Serialize Oxc-specific metadata as an `_oxc` field.
+    ///
+    /// This method behaves differently, depending on the serializer:
+    /// * `oxc_metadata() == true`: Behaves same as `serialize_field("_oxc", value)`.
+    /// * `oxc_metadata() == false`: Do nothing.
+    fn serialize_oxc_metadata<T: ESTree + ?Sized>(&mut self, value: &T);
+
     /// Finish serializing struct.
     fn end(self);
 
@@ -51,6 +58,12 @@ pub trait StructSerializer {
 
     /// Get whether output should contain `range` fields.
     fn ranges(&self) -> bool;
+
+    /// Get whether output should contain `_oxc` metadata fields.
+    fn oxc_metadata(&self) -> bool;
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    fn bigint_value_as_string(&self) -> bool;
 }
 
 /// Serializer for structs.
@@ -62,6 +75,11 @@ pub struct ESTreeStructSerializer<'s, C: Config, F: Formatter> {
     /// State of struct.
     /// Starts as `StructState::Empty`, transitions to `StructState::HasFields` on first field.
     state: StructState,
+    /// `true` if `serialize_span` has been called, which means this struct is an AST node.
+    has_span: bool,
+    /// Type of this node, if serializer has field renames for it.
+    /// Set when the `type` field is serialized.
+    renamed_node_type: Option<&'static str>,
 }
 
 impl<'s, C: Config, F: Formatter> ESTreeStructSerializer<'s, C, F> {
@@ -73,9 +91,41 @@ impl<'s, C: Config, F: Formatter> ESTreeStructSerializer<'s, C, F> {
             serializer.trace_path.push(TracePathPart::DUMMY);
         }
 
+        if let Some(CommentAttachment::Recording(recorder)) = serializer.comments.as_deref_mut() {
+            recorder.enter_struct();
+        }
+
         serializer.buffer_mut().print_ascii_byte(b'{');
 
-        Self { serializer, state: StructState::Empty }
+        Self { serializer, state: StructState::Empty, has_span: false, renamed_node_type: None }
+    }
+
+    /// Get name to output for field `key`, after applying the serializer's field renames.
+    #[inline]
+    fn field_name(&self, key: &'static str) -> &'static str {
+        let Some(node_type) = self.renamed_node_type else { return key };
+        self.serializer
+            .field_renames
+            .iter()
+            .find(|&&(rename_node_type, field, _)| rename_node_type == node_type && field == key)
+            .map_or(key, |&(_, _, new_name)| new_name)
+    }
+
+    /// Record type of this node, if serializer has field renames for it.
+    /// `value_start` is position in buffer where the value of `type` field starts.
+    fn record_node_type(&mut self, value_start: usize) {
+        // `type` field is a JSON string e.g. `"Program"`
+        let value = &self.serializer.buffer.as_bytes()[value_start..];
+        let Some(value) = value.strip_prefix(b"\"").and_then(|value| value.strip_suffix(b"\""))
+        else {
+            return;
+        };
+        self.renamed_node_type = self
+            .serializer
+            .field_renames
+            .iter()
+            .map(|&(node_type, _, _)| node_type)
+            .find(|node_type| node_type.as_bytes() == value);
     }
 }
 
@@ -87,12 +137,18 @@ impl<C: Config, F: Formatter> StructSerializer for ESTreeStructSerializer<'_, C,
     ///
     /// `key` must not contain any characters which require escaping in JSON.
     fn serialize_field<T: ESTree + ?Sized>(&mut self, key: &'static str, value: &T) {
+        let key = self.field_name(key);
+
         // Update last item in trace path to current key
         if self.serializer.config.fixes() {
             *self.serializer.trace_path.last_mut() = TracePathPart::Key(key);
         }
 
+        let omit_null_fields = self.serializer.omit_null_fields;
+        let prev_state = self.state;
+
         let (buffer, formatter) = self.serializer.buffer_and_formatter_mut();
+        let field_start = buffer.len();
         if self.state == StructState::Empty {
             self.state = StructState::HasFields;
             formatter.before_first_element(buffer);
@@ -103,7 +159,23 @@ impl<C: Config, F: Formatter> StructSerializer for ESTreeStructSerializer<'_, C,
 
         buffer.print_strs_array(["\"", key, "\":"]);
         formatter.before_field_value(buffer);
+        let value_start = buffer.len();
         value.serialize(&mut *self.serializer);
+
+        if key == "type" && !self.serializer.field_renames.is_empty() {
+            self.record_node_type(value_start);
+        }
+
+        if omit_null_fields {
+            let (buffer, formatter) = self.serializer.buffer_and_formatter_mut();
+            if &buffer.as_bytes()[value_start..] == b"null" {
+                buffer.truncate(field_start);
+                if prev_state == StructState::Empty {
+                    self.state = StructState::Empty;
+                    formatter.undo_first_element();
+                }
+            }
+        }
     }
 
     /// Serialize struct field which is JS syntax only (not in TS AST).
@@ -142,17 +214,54 @@ impl<C: Config, F: Formatter> StructSerializer for ESTreeStructSerializer<'_, C,
     ///
     /// * If `serializer.ranges() == true`, outputs `start`, `end`, and `range` fields.
     /// * Otherwise, outputs only `start` and `end`.
+    ///
+    /// If serializer was created with `with_loc`, also outputs a `loc` field after `end`.
     fn serialize_span<S: ESTreeSpan>(&mut self, span: S) {
         let range = span.range();
+        if let Some(CommentAttachment::Recording(recorder)) =
+            self.serializer.comments.as_deref_mut()
+        {
+            recorder.record_span(range);
+        }
+        self.has_span = true;
+
         self.serialize_field("start", &range[0]);
         self.serialize_field("end", &range[1]);
+        if let Some(line_table) = self.serializer.line_table.take() {
+            self.serialize_field("loc", &line_table.loc(range));
+            self.serializer.line_table = Some(line_table);
+        }
         if self.serializer.ranges() {
             self.serialize_field("range", &range);
         }
     }
 
+    /// Serialize Oxc-specific metadata as an `_oxc` field.
+    ///
+    /// This method behaves differently, depending on the serializer:
+    /// * `oxc_metadata() == true`: Behaves same as `serialize_field("_oxc", value)`.
+    /// * `oxc_metadata() == false`: Do nothing.
+    #[inline(always)]
+    fn serialize_oxc_metadata<T: ESTree + ?Sized>(&mut self, value: &T) {
+        if self.oxc_metadata() {
+            self.serialize_field("_oxc", value);
+        }
+    }
+
     /// Finish serializing struct.
-    fn end(self) {
+    fn end(mut self) {
+        if let Some(mut comments) = self.serializer.comments.take() {
+            match &mut *comments {
+                CommentAttachment::Recording(recorder) => recorder.exit_struct(),
+                CommentAttachment::Attaching(attached) => {
+                    if self.has_span {
+                        attached.serialize_node_comments(&mut self);
+                    }
+                }
+            }
+            self.serializer.comments = Some(comments);
+        }
+
         let mut serializer = self.serializer;
 
         // Pop entry for this struct from `trace_path`
@@ -181,6 +290,18 @@ impl<C: Config, F: Formatter> StructSerializer for ESTreeStructSerializer<'_, C,
     fn ranges(&self) -> bool {
         self.serializer.ranges()
     }
+
+    /// Get whether output should contain `_oxc` metadata fields.
+    #[inline(always)]
+    fn oxc_metadata(&self) -> bool {
+        self.serializer.oxc_metadata()
+    }
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    #[inline(always)]
+    fn bigint_value_as_string(&self) -> bool {
+        self.serializer.bigint_value_as_string()
+    }
 }
 
 /// State of [`StructSerializer`].
@@ -261,6 +382,18 @@ impl<'p, P: StructSerializer> Serializer for FlatStructSerializer<'p, P> {
         self.0.ranges()
     }
 
+    /// Get whether output should contain `_oxc` metadata fields.
+    #[inline(always)]
+    fn oxc_metadata(&self) -> bool {
+        self.0.oxc_metadata()
+    }
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    #[inline(always)]
+    fn bigint_value_as_string(&self) -> bool {
+        self.0.bigint_value_as_string()
+    }
+
     fn buffer_mut(&mut self) -> &mut CodeBuffer {
         const {
             panic!("Cannot flatten anything but a struct into another struct");
@@ -325,6 +458,13 @@ impl<P: StructSerializer> StructSerializer for FlatStructSerializer<'_, P> {
         self.0.serialize_span(span);
     }
 
+    /// Serialize Oxc-specific metadata as an `_oxc` field.
+    #[inline(always)]
+    fn serialize_oxc_metadata<T: ESTree + ?Sized>(&mut self, value: &T) {
+        // Delegate to parent `StructSerializer`
+        self.0.serialize_oxc_metadata(value);
+    }
+
     /// Finish serializing struct.
     fn end(self) {
         // No-op - there may be more fields to be added to the struct in the parent
@@ -341,6 +481,18 @@ impl<P: StructSerializer> StructSerializer for FlatStructSerializer<'_, P> {
     fn ranges(&self) -> bool {
         self.0.ranges()
     }
+
+    /// Get whether output should contain `_oxc` metadata fields.
+    #[inline(always)]
+    fn oxc_metadata(&self) -> bool {
+        self.0.oxc_metadata()
+    }
+
+    /// Get whether `value` field of `BigIntLiteral`s should be output as a string.
+    #[inline(always)]
+    fn bigint_value_as_string(&self) -> bool {
+        self.0.bigint_value_as_string()
+    }
 }
 
 /// Trait for `Span` to implement.
@@ -446,6 +598,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_struct_omit_null_fields() {
+        struct Foo {
+            first: Option<u32>,
+            name: &'static str,
+            middle: Option<u32>,
+            elements: [Option<u32>; 2],
+            inner: Inner,
+            last: Option<u32>,
+        }
+
+        struct Inner {
+            only: Option<u32>,
+        }
+
+        impl ESTree for Foo {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_field("first", &self.first);
+                state.serialize_field("name", &self.name);
+                state.serialize_field("middle", &self.middle);
+                state.serialize_field("elements", &self.elements);
+                state.serialize_field("inner", &self.inner);
+                state.serialize_field("last", &self.last);
+                state.end();
+            }
+        }
+
+        impl ESTree for Inner {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_field("only", &self.only);
+                state.end();
+            }
+        }
+
+        let foo = Foo {
+            first: None,
+            name: "null",
+            middle: None,
+            elements: [None, Some(1)],
+            inner: Inner { only: None },
+            last: None,
+        };
+
+        let mut serializer = CompactSerializer::default().with_omit_null_fields(true);
+        foo.serialize(&mut serializer);
+        let s = serializer.into_string();
+        // Array elements and strings containing `null` are not omitted
+        let expected = r#"{"name":"null","elements":[null,1],"inner":{}}"#;
+        assert_eq!(&s, expected);
+        assert_eq!(s.len(), 46);
+
+        let mut serializer = PrettySerializer::default().with_omit_null_fields(true);
+        foo.serialize(&mut serializer);
+        let s = serializer.into_string();
+        assert_eq!(
+            &s,
+            r#"{
+  "name": "null",
+  "elements": [
+    null,
+    1
+  ],
+  "inner": {}
+}"#
+        );
+    }
+
     #[test]
     fn serialize_flattened_struct() {
         struct Outer {
@@ -528,6 +749,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_flattened_struct_bigint_value_as_string() {
+        struct Outer {
+            inner: Inner,
+        }
+
+        struct Inner {
+            value: u32,
+        }
+
+        impl ESTree for Outer {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                self.inner.serialize(FlatStructSerializer(&mut state));
+                state.end();
+            }
+        }
+
+        impl ESTree for Inner {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let as_string = serializer.bigint_value_as_string();
+                let mut state = serializer.serialize_struct();
+                if as_string {
+                    state.serialize_field("value", &self.value.to_string());
+                } else {
+                    state.serialize_field("value", &self.value);
+                }
+                state.end();
+            }
+        }
+
+        let outer = Outer { inner: Inner { value: 123 } };
+
+        let mut serializer = CompactSerializer::default();
+        outer.serialize(&mut serializer);
+        assert_eq!(&serializer.into_string(), r#"{"value":123}"#);
+
+        let mut serializer = CompactSerializer::default().with_bigint_value_as_string(true);
+        outer.serialize(&mut serializer);
+        assert_eq!(&serializer.into_string(), r#"{"value":"123"}"#);
+    }
+
     #[test]
     fn serialize_struct_with_or_without_ts() {
         struct Foo {
@@ -584,4 +847,40 @@ mod tests {
 }"#
         );
     }
+
+    #[test]
+    fn serialize_span_as_integers() {
+        #[derive(Clone, Copy)]
+        struct Span {
+            start: u32,
+            end: u32,
+        }
+
+        impl ESTreeSpan for Span {
+            fn range(self) -> [u32; 2] {
+                [self.start, self.end]
+            }
+        }
+
+        struct Foo {
+            span: Span,
+        }
+
+        impl ESTree for Foo {
+            fn serialize<S: Serializer>(&self, serializer: S) {
+                let mut state = serializer.serialize_struct();
+                state.serialize_span(self.span);
+                state.end();
+            }
+        }
+
+        // Offsets above 2^24 can't be represented exactly as `f32`.
+        // Offsets must always be output as exact integers, with no fractional part.
+        let foo = Foo { span: Span { start: (1 << 24) + 1, end: u32::MAX } };
+
+        let mut serializer = CompactSerializer::new(false, true);
+        foo.serialize(&mut serializer);
+        let s = serializer.into_string();
+        assert_eq!(&s, r#"{"start":16777217,"end":4294967295,"range":[16777217,4294967295]}"#);
+    }
 }
@@ -0,0 +1,55 @@
+use oxc_allocator::Allocator;
+use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
+use oxc_estree_tokens::{ESTreeTokenOptionsJS, to_estree_tokens_json};
+use oxc_parser::{Parser, config::RuntimeParserConfig};
+use oxc_span::SourceType;
+
+fn tokens_json(source_text: &str) -> String {
+    let allocator = Allocator::new();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_config(RuntimeParserConfig::new(true))
+        .parse();
+    assert!(ret.diagnostics.is_empty());
+    let span_converter = Utf8ToUtf16::new(source_text);
+    to_estree_tokens_json(
+        &ret.tokens,
+        &ret.program,
+        source_text,
+        &span_converter,
+        ESTreeTokenOptionsJS,
+    )
+}
+
+#[test]
+fn variable_declaration() {
+    assert_eq!(
+        tokens_json("const x = 1;"),
+        concat!(
+            r#"[{"type":"Keyword","value":"const","start":0,"end":5},"#,
+            r#"{"type":"Identifier","value":"x","start":6,"end":7},"#,
+            r#"{"type":"Punctuator","value":"=","start":8,"end":9},"#,
+            r#"{"type":"Numeric","value":"1","start":10,"end":11},"#,
+            r#"{"type":"Punctuator","value":";","start":11,"end":12}]"#,
+        )
+    );
+}
+
+#[test]
+fn literals() {
+    assert_eq!(
+        tokens_json("f('a', `b${c}d`, /e/g);"),
+        concat!(
+            r#"[{"type":"Identifier","value":"f","start":0,"end":1},"#,
+            r#"{"type":"Punctuator","value":"(","start":1,"end":2},"#,
+            r#"{"type":"String","value":"'a'","start":2,"end":5},"#,
+            r#"{"type":"Punctuator","value":",","start":5,"end":6},"#,
+            r#"{"type":"Template","value":"`b${","start":7,"end":11},"#,
+            r#"{"type":"Identifier","value":"c","start":11,"end":12},"#,
+            r#"{"type":"Template","value":"}d`","start":12,"end":15},"#,
+            r#"{"type":"Punctuator","value":",","start":15,"end":16},"#,
+            r#"{"type":"RegularExpression","value":"/e/g","regex":{"pattern":"e","flags":"g"},"start":17,"end":21},"#,
+            r#"{"type":"Punctuator","value":")","start":21,"end":22},"#,
+            r#"{"type":"Punctuator","value":";","start":22,"end":23}]"#,
+        )
+    );
+}
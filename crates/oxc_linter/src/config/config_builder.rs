@@ -18,7 +18,7 @@ use crate::{
         external_plugins::ExternalPluginEntry,
         overrides::OxlintOverride,
         plugins::{LintPlugins, is_normal_plugin_name, normalize_plugin_name},
-        rules::OverrideRulesError,
+        rules::{OverrideRulesError, check_rule_names},
     },
     external_linter::ExternalLinter,
     external_plugin_store::{ExternalOptionsId, ExternalRuleId},
@@ -291,6 +291,10 @@ impl ConfigStoreBuilder {
             )?;
         }
 
+        if let Some(unfixable_rules) = &builder.config.options.unfixable_rules {
+            check_rule_names(unfixable_rules, external_plugin_store)?;
+        }
+
         Ok(builder)
     }
 
@@ -1651,6 +1655,32 @@ mod test {
         assert_eq!(err.to_string(), "Rule 'no-console-typo' not found in plugin 'eslint'");
     }
 
+    #[test]
+    fn test_unknown_unfixable_rule_errors() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"
+            {
+                "options": {
+                    "unfixableRules": ["eslint/no-debugger", "@typescript-eslint/no-explicit-any", "no-console-typo"]
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let err = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut external_plugin_store,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Rule 'no-console-typo' not found in plugin 'eslint'");
+    }
+
     #[test]
     fn test_unknown_builtin_rule_errors_in_overrides() {
         let oxlintrc: Oxlintrc = serde_json::from_str(
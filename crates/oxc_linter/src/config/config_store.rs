@@ -261,14 +261,18 @@ impl Config {
             Arc::new(config)
         };
 
-        let rules =
+        // Sort by rule id, like the base config, so rules run in the same order regardless of
+        // hash map iteration order.
+        let mut rules =
             rules.into_iter().filter(|(_, severity)| severity.is_warn_deny()).collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|(rule, _)| rule.id());
 
-        let external_rules = external_rules
+        let mut external_rules = external_rules
             .into_iter()
             .filter(|(_, (_, severity))| severity.is_warn_deny())
             .map(|(rule_id, (options_id, severity))| (rule_id, options_id, severity))
             .collect::<Vec<_>>();
+        external_rules.sort_unstable_by_key(|(rule_id, _, _)| *rule_id);
 
         ResolvedLinterState {
             rules: Arc::from(rules.into_boxed_slice()),
@@ -389,6 +393,16 @@ impl ConfigStore {
         self.base.base.config.options.respect_eslint_disable_directives.unwrap_or(true)
     }
 
+    /// Additional comment prefixes that act like `oxlint-disable-line`.
+    pub fn ignore_comment_prefixes(&self) -> &[String] {
+        self.base.base.config.options.ignore_comment_prefixes.as_deref().unwrap_or_default()
+    }
+
+    /// Rules whose fixes are never applied, configured in the root config.
+    pub fn unfixable_rules(&self) -> &[String] {
+        self.base.base.config.options.unfixable_rules.as_deref().unwrap_or_default()
+    }
+
     pub(crate) fn get_related_config(&self, path: &Path) -> &Config {
         if self.nested_configs.is_empty() {
             &self.base
@@ -614,6 +628,46 @@ mod test {
         assert_eq!(store.resolve("src/foo/bar/baz/App.spec.tsx".as_ref()).rules.len(), 2);
     }
 
+    #[test]
+    fn test_add_rule_only_for_test_files() {
+        let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
+            env: None,
+            files: GlobSet::new(vec!["**/*.test.js"]),
+            exclude_files: GlobSet::default(),
+            plugins: None,
+            globals: None,
+            rules: ResolvedOxlintOverrideRules {
+                builtin_rules: vec![(
+                    RuleEnum::EslintNoUnusedVars(EslintNoUnusedVars::default()),
+                    AllowWarnDeny::Deny,
+                )],
+                external_rules: vec![],
+            },
+        }]);
+
+        let store = ConfigStore::new(
+            Config::new(
+                vec![],
+                vec![],
+                OxlintCategories::default(),
+                LintConfig::default(),
+                overrides,
+            ),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+
+        assert!(store.resolve("src/index.js".as_ref()).rules.is_empty());
+        assert!(store.resolve("src/index.test.ts".as_ref()).rules.is_empty());
+
+        for path in ["index.test.js", "src/index.test.js", "src/foo/bar/index.test.js"] {
+            let rules = store.resolve(path.as_ref()).rules;
+            assert_eq!(rules.len(), 1, "expected rule to be enabled for {path}");
+            assert_eq!(rules[0].0.name(), "no-unused-vars");
+            assert_eq!(rules[0].1, AllowWarnDeny::Deny);
+        }
+    }
+
     #[test]
     fn test_number_of_rules_override_only() {
         let overrides = ResolvedOxlintOverrides::new(vec![ResolvedOxlintOverride {
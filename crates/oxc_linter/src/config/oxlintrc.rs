@@ -64,6 +64,22 @@ pub struct OxlintOptions {
     /// Only supported in the root configuration file.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub respect_eslint_disable_directives: Option<bool>,
+    /// Additional comment prefixes that suppress diagnostics on the same line,
+    /// the same way `oxlint-disable-line` does.
+    ///
+    /// For example, with `["oxlint-ignore"]`, `debugger; // oxlint-ignore no-debugger`
+    /// behaves like `debugger; // oxlint-disable-line no-debugger`.
+    /// Only supported in the root configuration file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_comment_prefixes: Option<Vec<String>>,
+    /// Rules whose fixes are never applied, even when fixing is enabled.
+    /// Diagnostics from these rules are still reported.
+    ///
+    /// Rule names are in the same format as in `rules`, e.g. `no-debugger` or `unicorn/no-null`.
+    /// Equivalent to passing `--unfixable-rule` on the CLI. Rules from both are combined.
+    /// Only supported in the root configuration file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfixable_rules: Option<Vec<String>>,
 }
 
 impl OxlintOptions {
@@ -75,6 +91,8 @@ impl OxlintOptions {
             && self.max_warnings.is_none()
             && self.report_unused_disable_directives.is_none()
             && self.respect_eslint_disable_directives.is_none()
+            && self.ignore_comment_prefixes.is_none()
+            && self.unfixable_rules.is_none()
     }
 
     #[must_use]
@@ -90,6 +108,11 @@ impl OxlintOptions {
             respect_eslint_disable_directives: self
                 .respect_eslint_disable_directives
                 .or(other.respect_eslint_disable_directives),
+            ignore_comment_prefixes: self
+                .ignore_comment_prefixes
+                .clone()
+                .or_else(|| other.ignore_comment_prefixes.clone()),
+            unfixable_rules: self.unfixable_rules.clone().or_else(|| other.unfixable_rules.clone()),
         }
     }
 }
@@ -344,11 +367,21 @@ impl Oxlintrc {
         Ok(config)
     }
 
+    /// Parse a config from a JSON string.
+    ///
+    /// Like [`Oxlintrc::from_file`], comments and trailing commas (JSONC) are accepted.
+    ///
     /// # Errors
     ///
     /// * Parse Failure
     pub fn from_string(json_string: &str) -> Result<Self, OxcDiagnostic> {
-        let json = serde_json::from_str::<serde_json::Value>(json_string)
+        // jsonc support
+        let mut json_string = json_string.to_string();
+        json_strip_comments::strip(&mut json_string).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse jsonc config: {err:?}"))
+        })?;
+
+        let json = serde_json::from_str::<serde_json::Value>(&json_string)
             .unwrap_or(serde_json::Value::Null);
 
         Self::deserialize(&json).map_err(|err| {
@@ -573,6 +606,12 @@ mod test {
         )
         .unwrap();
         assert_eq!(config.options.respect_eslint_disable_directives, Some(true));
+
+        let config: Oxlintrc = serde_json::from_value(
+            json!({ "options": { "ignoreCommentPrefixes": ["oxlint-ignore"] } }),
+        )
+        .unwrap();
+        assert_eq!(config.options.ignore_comment_prefixes, Some(vec!["oxlint-ignore".to_string()]));
     }
 
     #[test]
@@ -864,4 +903,32 @@ mod test {
             assert_eq!(entry.config_dir, new_config_dir);
         }
     }
+
+    #[test]
+    fn test_oxlintrc_from_string_jsonc() {
+        let jsonc = r#"{
+            // Line comment
+            "plugins": ["react", "oxc",],
+            /* Block comment */
+            "rules": {
+                "no-console": "error", // trailing comment
+                "eqeqeq": ["warn", "always",],
+            },
+        }"#;
+        let json = r#"{
+            "plugins": ["react", "oxc"],
+            "rules": {
+                "no-console": "error",
+                "eqeqeq": ["warn", "always"]
+            }
+        }"#;
+
+        let from_jsonc = Oxlintrc::from_string(jsonc).unwrap();
+        let from_json = Oxlintrc::from_string(json).unwrap();
+        assert_eq!(
+            serde_json::to_value(&from_jsonc).unwrap(),
+            serde_json::to_value(&from_json).unwrap()
+        );
+        assert_eq!(from_jsonc.rules.rules.len(), 2);
+    }
 }
@@ -659,6 +659,47 @@ fn parse_rule_key(name: &str) -> (String, String) {
     unalias_plugin_name(plugin_name, rule_name)
 }
 
+/// Get canonical `(plugin_name, rule_name)` of a rule named in the same format as keys of `rules`
+/// (e.g. `no-debugger`, `eslint/no-debugger`, `@typescript-eslint/no-explicit-any`).
+///
+/// The result matches [`MessageRule::plugin_name`] and [`MessageRule::rule_name`] of diagnostics
+/// reported by the rule.
+///
+/// [`MessageRule::plugin_name`]: crate::MessageRule::plugin_name
+/// [`MessageRule::rule_name`]: crate::MessageRule::rule_name
+pub fn canonical_rule_name(name: &str) -> (String, String) {
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    (plugin_name.to_string(), rule_name.to_string())
+}
+
+/// Check that all of `names` refer to existing rules.
+///
+/// Names are in the same format as keys of `rules`. As with `rules`, names of rules in
+/// non-builtin plugins are only checked if JS plugins are enabled.
+pub fn check_rule_names(
+    names: &[String],
+    external_plugin_store: &ExternalPluginStore,
+) -> Result<(), Vec<OverrideRulesError>> {
+    let mut errors = vec![];
+    for name in names {
+        let (plugin_name, rule_name) = canonical_rule_name(name);
+        if LintPlugins::try_from(plugin_name.as_str()).is_ok() {
+            if !RULES
+                .iter()
+                .any(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+            {
+                errors.push(OverrideRulesError::RuleNotFound { plugin_name, rule_name });
+            }
+        } else if external_plugin_store.is_enabled()
+            && let Err(e) = external_plugin_store.lookup_rule_id(&plugin_name, &rule_name)
+        {
+            errors.push(OverrideRulesError::ExternalRuleLookup(e));
+        }
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
 pub(super) fn unalias_plugin_name(plugin_name: &str, rule_name: &str) -> (String, String) {
     // First normalize the plugin name by stripping eslint-plugin- prefix/suffix
     let normalized = super::plugins::normalize_plugin_name(plugin_name);
@@ -1006,6 +1047,45 @@ mod test {
         assert!(r3.severity.is_warn_deny());
     }
 
+    #[test]
+    fn test_parse_rule_key_aliases() {
+        let cases: &[(&[&str], (&str, &str))] = &[
+            (&["no-unsafe-finally", "eslint/no-unsafe-finally"], ("eslint", "no-unsafe-finally")),
+            (
+                &[
+                    "typescript/no-namespace",
+                    "@typescript-eslint/no-namespace",
+                    "@typescript-eslint/eslint-plugin/no-namespace",
+                ],
+                ("typescript", "no-namespace"),
+            ),
+            (
+                &["import/no-cycle", "import-x/no-cycle", "eslint-plugin-import-x/no-cycle"],
+                ("import", "no-cycle"),
+            ),
+            (&["jsx-a11y/alt-text", "jsx_a11y/alt-text"], ("jsx_a11y", "alt-text")),
+            (&["react/rules-of-hooks", "react-hooks/rules-of-hooks"], ("react", "rules-of-hooks")),
+            (
+                &[
+                    "nextjs/google-font-display",
+                    "@next/google-font-display",
+                    "@next/next/google-font-display",
+                ],
+                ("nextjs", "google-font-display"),
+            ),
+        ];
+
+        for (names, (plugin_name, rule_name)) in cases {
+            for name in *names {
+                assert_eq!(
+                    super::parse_rule_key(name),
+                    ((*plugin_name).to_string(), (*rule_name).to_string()),
+                    "{name}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_external_rule_options_are_recorded() {
         // Register a fake external plugin and rule
@@ -215,7 +215,8 @@ mod test {
                     "Hyperlink",
                     {"name": "MyLink", "linkAttribute": "to"},
                     {"name": "Link", "linkAttribute": ["to", "href"]},
-                ]
+                ],
+                "version": "18.2"
             }
         }))
         .unwrap();
@@ -240,6 +241,10 @@ mod test {
             as_attrs(["to", "href"])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(
+            settings.react.version.map(|version| version.to_string()),
+            Some("18.2.0".into())
+        );
     }
 
     #[test]
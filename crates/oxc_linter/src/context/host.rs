@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     borrow::Cow,
     cell::{Cell, RefCell},
     ffi::OsStr,
@@ -7,6 +8,8 @@ use std::{
     sync::Arc,
 };
 
+use rustc_hash::FxHashMap;
+
 use oxc_allocator::ArenaBox;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_parser::Token;
@@ -17,10 +20,11 @@ use crate::{
     AllowWarnDeny, FrameworkFlags,
     config::{LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, OxlintSettings},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
-    fixer::{Fix, FixKind, Message, PossibleFixes},
+    fixer::{Fix, FixKind, Message, PossibleFixes, RuleMatch},
     frameworks::FrameworkOptions,
     module_record::ModuleRecord,
     options::LintOptions,
+    rule::CustomRule,
     rules::RuleEnum,
 };
 
@@ -70,6 +74,7 @@ impl<'a> ContextSubHost<'a> {
 
         let disable_directives = DisableDirectivesBuilder::new()
             .with_respect_eslint_disable_directives(options.respect_eslint_disable_directives)
+            .with_ignore_comment_prefixes(&options.ignore_comment_prefixes)
             .build(semantic.source_text(), semantic.comments());
 
         Self {
@@ -110,6 +115,7 @@ pub struct ContextSubHostOptions<'a> {
     pub framework_options: FrameworkOptions,
     pub parser_tokens: ArenaBox<'a, [Token]>,
     pub respect_eslint_disable_directives: bool,
+    pub ignore_comment_prefixes: Arc<[String]>,
 }
 
 impl Default for ContextSubHostOptions<'_> {
@@ -118,10 +124,17 @@ impl Default for ContextSubHostOptions<'_> {
             framework_options: FrameworkOptions::Default,
             parser_tokens: ArenaBox::new_empty_boxed_slice(),
             respect_eslint_disable_directives: true,
+            ignore_comment_prefixes: Arc::default(),
         }
     }
 }
 
+/// Callback which receives diagnostics as they are reported.
+type DiagnosticCallback<'a> = Box<dyn FnMut(Message) + 'a>;
+
+/// State of each rule, keyed by plugin and rule name.
+type RuleStates = FxHashMap<(&'static str, &'static str), Box<dyn Any>>;
+
 /// Stores shared information about a file being linted.
 ///
 /// When linting a file, there are a number of shared resources that are
@@ -152,6 +165,21 @@ pub struct ContextHost<'a> {
     ///
     /// Contains diagnostics for all rules across a single file.
     diagnostics: RefCell<Vec<Message>>,
+    /// State of each rule for the source being linted.
+    ///
+    /// See [`LintContext::rule_state`](super::LintContext::rule_state).
+    pub(super) rule_states: RefCell<RuleStates>,
+    /// Whether to collect lightweight [`RuleMatch`]es instead of full diagnostics.
+    ///
+    /// Set by [`Linter::run_analysis`](crate::Linter::run_analysis).
+    analysis_only: bool,
+    /// Rule matches reported by the linter, when `analysis_only` is `true`.
+    rule_matches: RefCell<Vec<RuleMatch>>,
+    /// If set, diagnostics are passed to this callback as they are reported,
+    /// instead of being collected in `diagnostics`.
+    ///
+    /// Set by [`Linter::run_with_callback`](crate::Linter::run_with_callback).
+    diagnostic_callback: Option<RefCell<DiagnosticCallback<'a>>>,
     /// Whether or not to apply code fixes during linting. Defaults to
     /// [`FixKind::None`] (no fixing).
     ///
@@ -198,6 +226,10 @@ impl<'a> ContextHost<'a> {
             sub_hosts,
             current_sub_host_index: Cell::new(0),
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
+            rule_states: RefCell::default(),
+            analysis_only: false,
+            rule_matches: RefCell::new(vec![]),
+            diagnostic_callback: None,
             fix: options.fix,
             file_path,
             file_extension,
@@ -207,6 +239,30 @@ impl<'a> ContextHost<'a> {
         .sniff_for_frameworks()
     }
 
+    /// Collect [`RuleMatch`]es instead of full diagnostics.
+    pub(crate) fn with_analysis_only(mut self) -> Self {
+        self.analysis_only = true;
+        self
+    }
+
+    /// Returns `true` if only [`RuleMatch`]es are collected, not full diagnostics.
+    #[inline]
+    pub(crate) fn is_analysis_only(&self) -> bool {
+        self.analysis_only
+    }
+
+    /// Pass diagnostics to `callback` as they are reported, instead of collecting them.
+    pub(crate) fn with_diagnostic_callback(mut self, callback: DiagnosticCallback<'a>) -> Self {
+        self.diagnostic_callback = Some(RefCell::new(callback));
+        self
+    }
+
+    /// Returns `true` if diagnostics are passed to a callback, instead of being collected.
+    #[inline]
+    pub(crate) fn has_diagnostic_callback(&self) -> bool {
+        self.diagnostic_callback.is_some()
+    }
+
     /// The current [`ContextSubHost`]
     pub fn current_sub_host(&self) -> &ContextSubHost<'a> {
         &self.sub_hosts[self.current_sub_host_index.get()]
@@ -301,12 +357,32 @@ impl<'a> ContextHost<'a> {
     /// by any rule to report issues.
     #[inline]
     pub(crate) fn push_diagnostic(&self, mut diagnostic: Message) {
+        if self.analysis_only {
+            // Diagnostics not produced by a rule (e.g. JS plugin errors) are not rule matches
+            if let Some(rule) = diagnostic.rule {
+                self.push_rule_match(RuleMatch { rule, span: diagnostic.span });
+            }
+            return;
+        }
         if self.current_sub_host().source_text_offset != 0 {
             diagnostic.move_offset(self.current_sub_host().source_text_offset);
         }
+        if let Some(callback) = &self.diagnostic_callback {
+            (callback.borrow_mut())(diagnostic);
+            return;
+        }
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Add a rule match. Only used when [`ContextHost::is_analysis_only`] is `true`.
+    pub(crate) fn push_rule_match(&self, mut rule_match: RuleMatch) {
+        let offset = self.current_sub_host().source_text_offset;
+        if offset != 0 {
+            rule_match.span = rule_match.span.move_right(offset);
+        }
+        self.rule_matches.borrow_mut().push(rule_match);
+    }
+
     // Append a list of diagnostics. Only used in report_unused_directives.
     fn append_diagnostics(&self, mut diagnostics: Vec<Message>) {
         if self.current_sub_host().source_text_offset != 0 {
@@ -315,9 +391,18 @@ impl<'a> ContextHost<'a> {
                 diagnostic.move_offset(offset);
             }
         }
+        if let Some(callback) = &self.diagnostic_callback {
+            diagnostics.into_iter().for_each(&mut *callback.borrow_mut());
+            return;
+        }
         self.diagnostics.borrow_mut().extend(diagnostics);
     }
 
+    /// Drop the state of all rules, before rules are run on a new source.
+    pub(crate) fn clear_rule_states(&self) {
+        self.rule_states.borrow_mut().clear();
+    }
+
     // move the context to the next sub host
     pub fn next_sub_host(&self) -> bool {
         let next_index = self.current_sub_host_index.get() + 1;
@@ -413,6 +498,11 @@ impl<'a> ContextHost<'a> {
         std::mem::take(&mut *messages)
     }
 
+    /// Take ownership of all rule matches collected during linting.
+    pub fn take_rule_matches(&self) -> Vec<RuleMatch> {
+        std::mem::take(&mut *self.rule_matches.borrow_mut())
+    }
+
     /// Take ownership of the disable directives from the first sub host.
     /// This consumes the `ContextHost`.
     ///
@@ -453,6 +543,25 @@ impl<'a> ContextHost<'a> {
         }
     }
 
+    /// Creates a new [`LintContext`] for a rule registered with [`crate::Linter::with_custom_rules`].
+    pub(crate) fn spawn_custom(
+        self: Rc<Self>,
+        rule: &dyn CustomRule,
+        severity: AllowWarnDeny,
+    ) -> LintContext<'a> {
+        let plugin_name = rule.plugin_name();
+
+        LintContext {
+            parent: self,
+            current_rule_name: rule.name(),
+            current_plugin_name: plugin_name,
+            current_plugin_display_name: plugin_display_name(plugin_name),
+            #[cfg(debug_assertions)]
+            current_rule_fix_capabilities: rule.fix(),
+            severity: severity.into(),
+        }
+    }
+
     /// Creates a new [`LintContext`] for testing purposes only.
     #[cfg(test)]
     pub(crate) fn spawn_for_test(self: Rc<Self>) -> LintContext<'a> {
@@ -1,6 +1,6 @@
 #![expect(rustdoc::private_intra_doc_links)] // useful for intellisense
 
-use std::{borrow::Cow, ffi::OsStr, ops::Deref, path::Path, rc::Rc};
+use std::{borrow::Cow, cell::RefMut, ffi::OsStr, ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::{GLOBALS, GLOBALS_BUILTIN, GLOBALS_ES2026};
 
@@ -17,7 +17,10 @@ use crate::{
     WEBSITE_BASE_RULES_URL,
     config::GlobalValue,
     disable_directives::DisableDirectives,
-    fixer::{Fix, FixKind, Message, MessageRule, PossibleFixes, RuleFix, RuleFixer},
+    fixer::{
+        Fix, FixKind, Message, MessageRule, PossibleFixes, RuleFix, RuleFixer, RuleMatch,
+        primary_span,
+    },
     frameworks::FrameworkOptions,
 };
 
@@ -188,6 +191,25 @@ impl<'a> LintContext<'a> {
             .map(|(a, _)| a as u32)
     }
 
+    /// State of the current rule for the source being linted, created with `T::default()` on
+    /// first use.
+    ///
+    /// Rules can fill it in `run` and read it in `run_once`, which runs after all nodes have been
+    /// visited, e.g. to report based on what was seen in the whole file.
+    ///
+    /// # Panics
+    /// If called with a different `T` for the same rule, or while the state is already borrowed.
+    pub fn rule_state<T: Default + 'static>(&self) -> RefMut<'_, T> {
+        let key = (self.current_plugin_name, self.current_rule_name);
+        RefMut::map(self.parent.rule_states.borrow_mut(), |states| {
+            states
+                .entry(key)
+                .or_insert_with(|| Box::new(T::default()))
+                .downcast_mut::<T>()
+                .expect("rule state was created with a different type")
+        })
+    }
+
     /// Path to the file currently being linted.
     #[inline]
     pub fn file_path(&self) -> &Path {
@@ -322,11 +344,33 @@ impl<'a> LintContext<'a> {
         self.parent.push_diagnostic(message);
     }
 
+    /// Record a [`RuleMatch`] for `diagnostic` instead of a full [`Message`].
+    /// Used when [`ContextHost::is_analysis_only`] is `true`.
+    ///
+    /// No fixes are computed, and the diagnostic is not annotated with the rule's code and URL.
+    fn add_rule_match(&self, diagnostic: &OxcDiagnostic) {
+        let span = primary_span(diagnostic);
+        if self.parent.disable_directives().contains(self.current_rule_name, span) {
+            return;
+        }
+        self.parent.push_rule_match(RuleMatch {
+            rule: MessageRule {
+                plugin_name: Cow::Borrowed(self.current_plugin_name),
+                rule_name: Cow::Borrowed(self.current_rule_name),
+            },
+            span,
+        });
+    }
+
     /// Report a lint rule violation.
     ///
     /// Use [`LintContext::diagnostic_with_fix`] to provide an automatic fix.
     #[inline]
     pub fn diagnostic(&self, diagnostic: OxcDiagnostic) {
+        if self.parent.is_analysis_only() {
+            self.add_rule_match(&diagnostic);
+            return;
+        }
         self.add_diagnostic(
             Message::new(diagnostic, PossibleFixes::None)
                 .with_section_offset(self.parent.current_sub_host().source_text_offset),
@@ -439,6 +483,10 @@ impl<'a> LintContext<'a> {
         C: Into<RuleFix>,
         F: FnOnce(RuleFixer<'_, 'a>) -> C,
     {
+        if self.parent.is_analysis_only() {
+            self.add_rule_match(&diagnostic);
+            return;
+        }
         let (diagnostic, fix) = self.create_fix(fix_kind, fix, diagnostic);
         self.emit_single_fix(diagnostic, fix);
     }
@@ -469,6 +517,10 @@ impl<'a> LintContext<'a> {
         F1: FnOnce(RuleFixer<'_, 'a>) -> C,
         F2: FnOnce(RuleFixer<'_, 'a>) -> C,
     {
+        if self.parent.is_analysis_only() {
+            self.add_rule_match(&diagnostic);
+            return;
+        }
         let fixes_result: Vec<Fix> = vec![
             self.create_fix(fix_one.0, fix_one.1, diagnostic.clone()).1,
             self.create_fix(fix_two.0, fix_two.1, diagnostic.clone()).1,
@@ -498,6 +550,10 @@ impl<'a> LintContext<'a> {
     where
         I: IntoIterator<Item = RuleFix>,
     {
+        if self.parent.is_analysis_only() {
+            self.add_rule_match(&diagnostic);
+            return;
+        }
         let fixes_result: Vec<Fix> = suggestions
             .into_iter()
             .filter_map(|rule_fix| {
@@ -629,3 +685,187 @@ fn plugin_display_name(plugin_name: &'static str) -> &'static str {
         _ => plugin_name,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::Severity;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{
+        AllowWarnDeny, ConfigStore, ConfigStoreBuilder, ContextHost, ContextSubHost,
+        ContextSubHostOptions, ExternalPluginStore, FixKind, LintOptions, Linter, Message,
+        ModuleRecord, Oxlintrc, ResolvedLinterState, RuleMatch,
+        rules::{EslintNoDebugger, EslintNoUnsafeFinally, RuleEnum},
+    };
+
+    fn linter(rules: Vec<RuleEnum>, severity: AllowWarnDeny) -> Linter {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = rules
+            .into_iter()
+            .fold(ConfigStoreBuilder::empty(), |builder, rule| builder.with_rule(rule, severity))
+            .build(&mut external_plugin_store)
+            .unwrap();
+        Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+    }
+
+    fn sub_host<'a>(allocator: &'a Allocator, source: &'a str) -> ContextSubHost<'a> {
+        let parser_ret = Parser::new(allocator, source, SourceType::default()).parse();
+        assert!(parser_ret.diagnostics.is_empty(), "Parse error in: {source}");
+        let program = allocator.alloc(parser_ret.program);
+        let semantic = SemanticBuilder::new_linter().build(program).semantic;
+        ContextSubHost::new(
+            semantic,
+            Arc::new(ModuleRecord::default()),
+            0,
+            ContextSubHostOptions::default(),
+        )
+    }
+
+    fn lint(source: &str, severity: AllowWarnDeny) -> Vec<Message> {
+        let allocator = Allocator::default();
+        let linter = linter(vec![RuleEnum::EslintNoUnsafeFinally(EslintNoUnsafeFinally)], severity);
+        linter.run(Path::new("test.js"), vec![sub_host(&allocator, source)], &allocator)
+    }
+
+    fn lint_with_severity(source: &str, severity: AllowWarnDeny) -> Vec<Severity> {
+        lint(source, severity).into_iter().map(|message| message.error.severity).collect()
+    }
+
+    #[test]
+    fn test_rule_severity_override() {
+        let source = "function foo() { try {} finally { return 1; } }";
+
+        // `no-unsafe-finally` is a `correctness` rule, which is a warning unless configured otherwise.
+        assert_eq!(lint_with_severity(source, AllowWarnDeny::Warn), vec![Severity::Warning]);
+        assert_eq!(lint_with_severity(source, AllowWarnDeny::Deny), vec![Severity::Error]);
+    }
+
+    #[test]
+    fn test_diagnostic_with_multiple_labels() {
+        let source = "function foo() { try {} finally { return 1; } }";
+
+        let messages = lint(source, AllowWarnDeny::Warn);
+        assert_eq!(messages.len(), 1);
+        let labels = messages[0].error.labels.as_slice();
+        assert_eq!(labels.len(), 2);
+
+        // The statement is the primary label, and the enclosing `finally` keyword is secondary.
+        assert!(labels[0].primary());
+        assert_eq!((labels[0].offset(), labels[0].len()), (34, 9));
+        assert_eq!(
+            labels[0].label(),
+            Some("This statement overrides the `try` and `catch` blocks")
+        );
+        assert!(!labels[1].primary());
+        assert_eq!((labels[1].offset(), labels[1].len()), (24, 7));
+        assert_eq!(labels[1].label(), Some("Inside this `finally` block"));
+    }
+
+    #[test]
+    fn test_callback_receives_diagnostics() {
+        let source = "debugger;
+            function foo() { try {} finally { return 1; } }
+            // eslint-disable-next-line no-debugger
+            debugger;
+            if (x) debugger;";
+        let rules = vec![
+            RuleEnum::EslintNoDebugger(EslintNoDebugger),
+            RuleEnum::EslintNoUnsafeFinally(EslintNoUnsafeFinally),
+        ];
+        let allocator = Allocator::default();
+        let path = Path::new("test.js");
+        let linter = linter(rules, AllowWarnDeny::Warn).with_fix(FixKind::All);
+
+        let messages = linter.run(path, vec![sub_host(&allocator, source)], &allocator);
+        let mut streamed = vec![];
+        linter.run_with_callback(
+            path,
+            vec![sub_host(&allocator, source)],
+            &allocator,
+            &mut |message| streamed.push(message),
+        );
+
+        // The disabled `debugger` statement is not reported
+        assert_eq!(streamed.len(), 3);
+        assert_eq!(streamed, messages);
+    }
+
+    #[test]
+    fn test_analysis_only_matches_diagnostics() {
+        let source = "debugger;
+            function foo() { try {} finally { return 1; } }
+            // eslint-disable-next-line no-debugger
+            debugger;
+            if (x) debugger;";
+        let rules = || {
+            vec![
+                RuleEnum::EslintNoDebugger(EslintNoDebugger),
+                RuleEnum::EslintNoUnsafeFinally(EslintNoUnsafeFinally),
+            ]
+        };
+        let allocator = Allocator::default();
+        let path = Path::new("test.js");
+
+        let linter = linter(rules(), AllowWarnDeny::Warn).with_fix(FixKind::All);
+        let messages = linter.run(path, vec![sub_host(&allocator, source)], &allocator);
+        let matches = linter.run_analysis(path, vec![sub_host(&allocator, source)], &allocator);
+
+        let from_messages = messages
+            .into_iter()
+            .map(|message| RuleMatch { rule: message.rule.unwrap(), span: message.span })
+            .collect::<Vec<_>>();
+        assert_eq!(from_messages.len(), 3);
+        assert_eq!(matches, from_messages);
+    }
+
+    #[test]
+    fn test_settings_from_config() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"{
+                "plugins": ["react"],
+                "settings": { "react": { "version": "18.2.0" } },
+                "rules": { "react/display-name": "error" }
+            }"#,
+        )
+        .unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut external_plugin_store,
+            None,
+        )
+        .unwrap()
+        .build(&mut external_plugin_store)
+        .unwrap();
+        let store = ConfigStore::new(config, FxHashMap::default(), external_plugin_store);
+
+        let path = Path::new("test.jsx");
+        let ResolvedLinterState { rules, config, .. } = store.resolve(path);
+        let (rule, severity) =
+            rules.iter().find(|(rule, _)| rule.name() == "display-name").unwrap();
+
+        let allocator = Allocator::default();
+        let host = Rc::new(ContextHost::new(
+            path,
+            vec![sub_host(&allocator, "")],
+            LintOptions::default(),
+            config,
+        ));
+        let ctx = host.spawn(rule, *severity);
+        let version = ctx.settings().react.version.as_ref().map(ToString::to_string);
+        assert_eq!(version.as_deref(), Some("18.2.0"));
+    }
+}
@@ -440,9 +440,11 @@ impl DisableDirectives {
     }
 }
 
-pub struct DisableDirectivesBuilder {
+pub struct DisableDirectivesBuilder<'p> {
     /// Which directive prefixes should be recognized.
     respect_eslint_disable_directives: bool,
+    /// Additional prefixes that behave like `oxlint-disable-line`.
+    ignore_comment_prefixes: &'p [String],
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule>,
     /// Start of `eslint-disable` or `oxlint-disable`
@@ -455,10 +457,11 @@ pub struct DisableDirectivesBuilder {
     unused_enable_comments: Vec<(DirectivePrefix, Option<String>, Span)>,
 }
 
-impl DisableDirectivesBuilder {
+impl<'p> DisableDirectivesBuilder<'p> {
     pub fn new() -> Self {
         Self {
             respect_eslint_disable_directives: true,
+            ignore_comment_prefixes: &[],
             intervals: Lapper::new(vec![]),
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
@@ -476,6 +479,14 @@ impl DisableDirectivesBuilder {
         self
     }
 
+    /// Recognize comments starting with any of `prefixes` as `oxlint-disable-line` directives,
+    /// e.g. `debugger; // oxlint-ignore no-debugger` with the `oxlint-ignore` prefix.
+    #[must_use]
+    pub fn with_ignore_comment_prefixes(mut self, prefixes: &'p [String]) -> Self {
+        self.ignore_comment_prefixes = prefixes;
+        self
+    }
+
     pub fn build(mut self, source_text: &str, comments: &[Comment]) -> DisableDirectives {
         self.build_impl(source_text, comments);
 
@@ -822,6 +833,15 @@ impl DisableDirectivesBuilder {
             text.is_empty() || text.starts_with(char::is_whitespace)
         }
 
+        if let Some(rest) = self
+            .ignore_comment_prefixes
+            .iter()
+            .filter_map(|prefix| text.strip_prefix(prefix.as_str()))
+            .find(|rest| is_directive_end(rest))
+        {
+            return Some((DirectivePrefix::Oxlint, DirectiveKind::DisableLine, rest));
+        }
+
         let directive_prefix = DirectivePrefix::from_directive_text(text)?;
 
         if directive_prefix == DirectivePrefix::Eslint && !self.respect_eslint_disable_directives {
@@ -1613,6 +1633,44 @@ mod tests {
         assert!(directives.unused_enable_comments().is_empty());
     }
 
+    #[test]
+    #[expect(clippy::cast_possible_truncation)]
+    fn ignore_comment_prefixes_disable_line() {
+        let allocator = Allocator::default();
+        let source_text = r"
+            debugger; // oxlint-ignore no-debugger
+            debugger;
+            console.log(); // oxlint-ignore
+            console.log();
+            console.log(); // oxlint-ignored no-console
+        ";
+        let semantic = process_source(&allocator, source_text);
+        let prefixes = ["oxlint-ignore".to_string()];
+        let directives = DisableDirectivesBuilder::new()
+            .with_ignore_comment_prefixes(&prefixes)
+            .build(semantic.source_text(), semantic.comments());
+
+        let debugger_start = source_text.find("debugger;").unwrap() as u32;
+        let second_debugger_start = source_text.rfind("debugger;").unwrap() as u32;
+        let console_start = source_text.find("console.log").unwrap() as u32;
+        let second_console_start = console_start
+            + source_text[console_start as usize + 1..].find("console.log").unwrap() as u32
+            + 1;
+        let third_console_start = source_text.rfind("console.log").unwrap() as u32;
+
+        assert!(directives.contains("no-debugger", Span::sized(debugger_start, 8)));
+        assert!(!directives.contains("no-console", Span::sized(debugger_start, 8)));
+        assert!(!directives.contains("no-debugger", Span::sized(second_debugger_start, 8)));
+        assert!(directives.contains("no-console", Span::sized(console_start, 11)));
+        assert!(!directives.contains("no-console", Span::sized(second_console_start, 11)));
+        assert!(!directives.contains("no-console", Span::sized(third_console_start, 11)));
+
+        // Without the prefix configured, the comments are not directives.
+        let directives =
+            DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
+        assert!(!directives.contains("no-debugger", Span::sized(debugger_start, 8)));
+    }
+
     #[test]
     fn unused_disable_all() {
         test_directives(
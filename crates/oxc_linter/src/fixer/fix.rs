@@ -609,6 +609,8 @@ impl CompositeFix {
 
     /// Merges multiple fixes to one.
     ///
+    /// Insertions at the same offset are applied in the order they were pushed.
+    ///
     /// # Errors
     ///
     /// Returns a [`MergeFixesError`] error if any of:
@@ -627,7 +629,7 @@ impl CompositeFix {
             return Ok(fixes.pop().unwrap());
         }
 
-        fixes.sort_unstable_by_key(|a| a.span);
+        fixes.sort_by_key(|a| a.span);
 
         // safe, as fixes.len() > 1
         let start = fixes[0].span.start;
@@ -259,6 +259,62 @@ pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message>,
+    /// Fixes that were skipped because they overlap a fix from a different rule.
+    pub conflicts: Vec<FixConflict>,
+}
+
+impl FixResult<'_> {
+    /// Add a note to each remaining message whose fix was skipped because it overlaps a fix
+    /// from another rule, so the conflict is visible when the message is reported.
+    pub fn note_conflicts(&mut self) {
+        for conflict in &self.conflicts {
+            let Some(message) = self.messages.iter_mut().find(|m| {
+                m.rule == conflict.skipped_rule && m.fixes.span() == conflict.skipped_span
+            }) else {
+                continue;
+            };
+            let applied = conflict.applied_rule.as_ref().map_or_else(
+                || "another rule".to_string(),
+                |rule| format!("`{}`", rule.short_canonical_name()),
+            );
+            let note = format!(
+                "The fix for this problem was not applied because it overlaps a fix from {applied}."
+            );
+            message.error.note = Some(match message.error.note.take() {
+                Some(existing) => format!("{existing}\n{note}").into(),
+                None => note.into(),
+            });
+        }
+    }
+}
+
+/// A single edit applied by the [`Fixer`]. Spans refer to the original source text.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FixEdit {
+    pub span: Span,
+    pub content: Cow<'static, str>,
+}
+
+/// Result of [`Fixer::dry_run`].
+#[derive(Debug)]
+pub struct FixDryRun<'a> {
+    /// Edits that would be applied, in source order.
+    pub edits: Vec<FixEdit>,
+    /// The source text after applying `edits`.
+    pub fixed_code: Cow<'a, str>,
+}
+
+/// A fix that was not applied because it overlaps a fix applied for another rule.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FixConflict {
+    /// The rule whose fix was applied.
+    pub applied_rule: Option<MessageRule>,
+    /// Span of the applied fix.
+    pub applied_span: Span,
+    /// The rule whose fix was skipped.
+    pub skipped_rule: Option<MessageRule>,
+    /// Span of the skipped fix.
+    pub skipped_span: Span,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -274,14 +330,7 @@ pub struct Message {
 
 impl Message {
     pub fn new(error: OxcDiagnostic, fixes: PossibleFixes) -> Self {
-        let span = error
-            .labels
-            .iter()
-            .find(|span| span.primary())
-            .or_else(|| error.labels.first())
-            .map(|span| Span::new(span.offset(), span.offset() + span.len()))
-            .unwrap_or_default();
-
+        let span = primary_span(&error);
         Self { error, span, fixes, fixed: false, section_offset: 0, rule: None }
     }
 
@@ -323,6 +372,27 @@ impl Message {
     }
 }
 
+/// Span of the primary label of `error`, or its first label if none is marked as primary.
+pub fn primary_span(error: &OxcDiagnostic) -> Span {
+    error
+        .labels
+        .iter()
+        .find(|span| span.primary())
+        .or_else(|| error.labels.first())
+        .map(|span| Span::new(span.offset(), span.offset() + span.len()))
+        .unwrap_or_default()
+}
+
+/// Lightweight record of a rule reporting a diagnostic, collected instead of a [`Message`]
+/// by [`Linter::run_analysis`](crate::Linter::run_analysis).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleMatch {
+    /// The rule which reported the diagnostic.
+    pub rule: MessageRule,
+    /// Span of the diagnostic's primary label.
+    pub span: Span,
+}
+
 impl From<Message> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
@@ -375,21 +445,48 @@ impl<'a> Fixer<'a> {
         self
     }
 
+    /// Applies fixes in order of their span, skipping any fix that overlaps an already applied one.
+    ///
+    /// Fixes with the same span are ordered by rule name (`{plugin}/{rule}`), so the result does
+    /// not depend on the order in which rules ran. Fixes skipped because they overlap a fix from
+    /// another rule are reported in [`FixResult::conflicts`].
+    ///
     /// # Panics
-    pub fn fix(mut self) -> FixResult<'a> {
+    pub fn fix(self) -> FixResult<'a> {
+        self.apply(None)
+    }
+
+    /// Computes the edits [`Fixer::fix`] would apply, and the resulting code, without
+    /// the caller having to handle the remaining messages.
+    ///
+    /// Useful for previewing what `--fix` would change.
+    pub fn dry_run(self) -> FixDryRun<'a> {
+        let mut edits = vec![];
+        let FixResult { fixed_code, .. } = self.apply(Some(&mut edits));
+        FixDryRun { edits, fixed_code }
+    }
+
+    /// Implementation of [`Fixer::fix`]. Applied edits are pushed to `edits` if provided.
+    fn apply(mut self, mut edits: Option<&mut Vec<FixEdit>>) -> FixResult<'a> {
         let source_text = self.source_text;
         if self.messages.iter().all(|m| m.fixes.is_empty()) {
             return FixResult {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                conflicts: vec![],
             };
         }
 
-        self.messages.sort_unstable_by_key(|m| m.fixes.span());
+        self.messages.sort_by(|a, b| {
+            a.fixes.span().cmp(&b.fixes.span()).then_with(|| rule_key(a).cmp(&rule_key(b)))
+        });
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: u32 = 0;
+        // Span and rule of the most recently applied fix, the only one a later fix can overlap.
+        let mut last_applied: Option<(Span, Option<MessageRule>)> = None;
+        let mut conflicts = vec![];
 
         // only keep messages that were not fixed
         let mut filtered_messages = Vec::with_capacity(self.messages.len());
@@ -421,6 +518,16 @@ impl<'a> Fixer<'a> {
             // would incorrectly be considered as overlapping.
             let overlaps = fixed && last_pos >= start;
             if overlaps {
+                if let Some((applied_span, applied_rule)) = &last_applied
+                    && *applied_rule != m.rule
+                {
+                    conflicts.push(FixConflict {
+                        applied_rule: applied_rule.clone(),
+                        applied_span: *applied_span,
+                        skipped_rule: m.rule.clone(),
+                        skipped_span: *span,
+                    });
+                }
                 filtered_messages.push(m);
                 continue;
             }
@@ -430,7 +537,11 @@ impl<'a> Fixer<'a> {
             let offset = last_pos as usize;
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
+            if let Some(edits) = edits.as_deref_mut() {
+                edits.push(FixEdit { span: *span, content: content.clone() });
+            }
             last_pos = end;
+            last_applied = Some((*span, m.rule.clone()));
         }
 
         output.push_str(&source_text[last_pos as usize..]);
@@ -440,15 +551,11 @@ impl<'a> Fixer<'a> {
         #[cfg(debug_assertions)]
         if fixed && let Some(source_type) = self.source_type {
             use oxc_allocator::Allocator;
-            use oxc_parser::{ParseOptions, Parser};
+            use oxc_parser::Parser;
 
             let allocator = Allocator::default();
             let parse_result = Parser::new(&allocator, &output, source_type)
-                .with_options(ParseOptions {
-                    parse_regular_expression: true,
-                    allow_return_outside_function: true,
-                    ..ParseOptions::default()
-                })
+                .with_options(crate::parse_options())
                 .parse();
             debug_assert!(
                 parse_result.diagnostics.is_empty() && !parse_result.panicked,
@@ -457,22 +564,34 @@ impl<'a> Fixer<'a> {
             );
         }
 
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
+        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages, conflicts }
     }
 }
 
+/// Sort key used to order fixes for the same span by the rule that produced them.
+fn rule_key(message: &Message) -> Option<(&str, &str)> {
+    message.rule.as_ref().map(|r| (r.plugin_name.as_ref(), r.rule_name.as_ref()))
+}
+
 #[cfg(test)]
 mod test {
-    use std::borrow::Cow;
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
 
     use cow_utils::CowUtils;
+    use oxc_allocator::Allocator;
     use oxc_diagnostics::OxcDiagnostic;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
     use oxc_span::{SourceType, Span};
 
-    use crate::FixKind;
+    use crate::{
+        FixKind, LintOptions, ModuleRecord,
+        context::{ContextHost, ContextSubHost, ContextSubHostOptions},
+    };
 
     use super::{
-        CompositeFix, Fix, FixResult, Fixer, Message, PossibleFixes, format_replace_message,
+        CompositeFix, Fix, FixConflict, FixEdit, FixResult, Fixer, Message, MessageRule,
+        PossibleFixes, RuleFixer, format_replace_message,
     };
 
     fn insert_at_end() -> OxcDiagnostic {
@@ -778,6 +897,71 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_fix_for_same_span_by_rule_name_and_record_conflict() {
+        let rule = |rule_name: &'static str| MessageRule {
+            plugin_name: Cow::Borrowed("eslint"),
+            rule_name: Cow::Borrowed(rule_name),
+        };
+        let rename_to = |name: &'static str| Fix { content: Cow::Borrowed(name), ..REPLACE_ID };
+        let a = create_message(replace_id(), PossibleFixes::Single(rename_to("a")))
+            .with_rule(rule("a-rule"));
+        let b = create_message(replace_id(), PossibleFixes::Single(rename_to("b")))
+            .with_rule(rule("b-rule"));
+
+        let result1 = get_fix_result(vec![a.clone(), b.clone()]);
+        let result2 = get_fix_result(vec![b, a]);
+        for result in [&result1, &result2] {
+            assert_eq!(result.fixed_code, "var a = 6 * 7;");
+            assert_eq!(result.messages.len(), 1);
+            assert_eq!(result.messages[0].rule, Some(rule("b-rule")));
+            assert_eq!(
+                result.conflicts,
+                vec![FixConflict {
+                    applied_rule: Some(rule("a-rule")),
+                    applied_span: REPLACE_ID.span,
+                    skipped_rule: Some(rule("b-rule")),
+                    skipped_span: REPLACE_ID.span,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn dry_run_returns_edits_and_fixed_code() {
+        let messages = vec![
+            create_message(replace_num(), PossibleFixes::Single(REPLACE_NUM)),
+            create_message(replace_var(), PossibleFixes::Single(REPLACE_VAR)),
+            create_message(no_fix(Span::new(4, 10)), PossibleFixes::None),
+        ];
+        let result = Fixer::new(TEST_CODE, messages, Some(SourceType::default())).dry_run();
+        assert_eq!(
+            result.edits,
+            vec![
+                FixEdit { span: Span::new(0, 3), content: Cow::Borrowed("let") },
+                FixEdit { span: Span::new(13, 14), content: Cow::Borrowed("5") },
+            ]
+        );
+        assert_eq!(result.fixed_code, "let answer = 5 * 7;");
+
+        // Applying the edits manually, from last to first, gives the same code
+        let mut manual = TEST_CODE.to_string();
+        for edit in result.edits.iter().rev() {
+            manual.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.content);
+        }
+        assert_eq!(result.fixed_code, manual);
+    }
+
+    #[test]
+    fn no_conflict_for_overlapping_fixes_from_same_rule() {
+        let result = get_fix_result(vec![
+            create_message(remove_middle(Span::default()), PossibleFixes::Single(REMOVE_MIDDLE)),
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)),
+        ]);
+        assert_eq!(result.messages.len(), 1);
+        assert!(result.conflicts.is_empty());
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result =
@@ -890,6 +1074,21 @@ mod test {
         assert_fixes_merged(vec![fix.clone()], &fix, source_text);
     }
 
+    #[test]
+    fn keep_order_of_insertions_at_same_offset() {
+        let source_text = "foo\nbar";
+        let fixes = vec![
+            Fix::new("a", Span::empty(3)),
+            Fix::new("b", Span::empty(3)),
+            Fix::new("c", Span::empty(3)),
+        ];
+        assert_fixes_merged(fixes, &Fix::new("abc", Span::empty(3)), source_text);
+
+        // An insertion at the start of a replaced range goes before the replacement.
+        let fixes = vec![Fix::new("baz", Span::new(4, 7)), Fix::new("(", Span::empty(4))];
+        assert_fixes_merged(fixes, &Fix::new("(baz", Span::new(4, 7)), source_text);
+    }
+
     #[test]
     #[should_panic(expected = "Fix must not be overlapped, last_pos: 3, span.start: 2")]
     fn throw_error_when_ranges_overlap() {
@@ -954,6 +1153,66 @@ mod test {
         assert_eq!(result.fixed_code, "let answer = 42;");
     }
 
+    fn with_rule_fixer(source_text: &'static str, f: impl FnOnce(RuleFixer<'_, '_>)) {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let program = allocator.alloc(parser_ret.program);
+        let semantic = SemanticBuilder::new_linter().build(program).semantic;
+        let ctx = Rc::new(ContextHost::new(
+            "test.js",
+            vec![ContextSubHost::new(
+                semantic,
+                Arc::new(ModuleRecord::default()),
+                0,
+                ContextSubHostOptions::default(),
+            )],
+            LintOptions::default(),
+            Arc::default(),
+        ))
+        .spawn_for_test();
+        f(RuleFixer::new(FixKind::SafeFix, &ctx));
+    }
+
+    #[test]
+    fn rule_fixer_helpers() {
+        let source_text = "var answer = 6 * 7;";
+        let answer = Span::new(4, 10);
+        with_rule_fixer(source_text, |fixer| {
+            let cases = [
+                (fixer.insert_text_before_range(answer, "the"), Span::empty(4), "the"),
+                (fixer.insert_text_after_range(answer, "42"), Span::empty(10), "42"),
+                (fixer.insert_text_before(&answer, "the"), Span::empty(4), "the"),
+                (fixer.insert_text_after(&answer, "42"), Span::empty(10), "42"),
+                (fixer.replace(answer, "foo"), answer, "foo"),
+                (fixer.replace_with(&answer, &Span::new(0, 3)), answer, "var"),
+                (fixer.delete_range(answer), answer, ""),
+                (fixer.delete(&answer), answer, ""),
+            ];
+            for (rule_fix, span, content) in cases {
+                assert_eq!(rule_fix.kind(), FixKind::SafeFix);
+                let fix = rule_fix.into_fix(source_text);
+                assert_eq!(fix.span, span);
+                assert_eq!(fix.content, content);
+            }
+        });
+    }
+
+    #[test]
+    fn rule_fixer_insertions_at_same_offset() {
+        let source_text = "foo;";
+        with_rule_fixer(source_text, |fixer| {
+            let fixer = fixer.for_multifix();
+            let foo = Span::new(0, 3);
+            let mut rule_fix = fixer.new_fix_with_capacity(3);
+            rule_fix.push(fixer.insert_text_after_range(foo, "("));
+            rule_fix.push(fixer.insert_text_after_range(foo, ")"));
+            rule_fix.push(fixer.insert_text_before_range(foo, "void "));
+            let fix = rule_fix.into_fix(source_text);
+            assert_eq!(fix.span, foo);
+            assert_eq!(fix.content, "void foo()");
+        });
+    }
+
     #[test]
     fn format_replace_message_for_non_empty_replacement() {
         assert_eq!(format_replace_message("{foo}", "bar"), "Replace `{foo}` with `bar`.");
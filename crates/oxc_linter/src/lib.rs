@@ -13,21 +13,23 @@ use std::{
     ptr::{self, NonNull},
     rc::Rc,
     string::ToString,
+    sync::Arc,
 };
 
 use oxc_allocator::{Allocator, AllocatorPool, ArenaVec, CloneIn, TakeIn};
 use oxc_ast::{
-    ast::{Comment, CommentContent, CommentKind, Program},
+    ast::{Comment, CommentContent, CommentKind, Program, Statement},
     ast_kind::AST_TYPE_MAX,
+    builder::AstBuilder,
 };
 use oxc_ast_macros::ast;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_data_structures::box_macros::boxed_array;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_estree_tokens::{ESTreeTokenOptionsJS, update_tokens};
-use oxc_parser::Token;
-use oxc_semantic::{AstNode, Semantic};
-use oxc_span::Span;
+use oxc_parser::{ParseOptions, Parser, Token};
+use oxc_semantic::{AstNode, Semantic, SemanticBuilder};
+use oxc_span::{GetSpan, SourceType, Span};
 
 mod ast_util;
 mod config;
@@ -43,6 +45,7 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod source_map;
 mod suppression;
 pub(crate) mod timing;
 mod tsgolint;
@@ -81,22 +84,28 @@ pub use crate::{
         JsFix, LintFileResult, LoadPluginResult, convert_and_merge_js_fixes,
     },
     external_plugin_store::{ExternalOptionsId, ExternalPluginStore, ExternalRuleId},
-    fixer::{Fix, FixKind, Fixer, Message, MessageRule, PossibleFixes},
+    fixer::{
+        Fix, FixConflict, FixDryRun, FixEdit, FixKind, Fixer, Message, MessageRule, PossibleFixes,
+        RuleMatch,
+    },
     frameworks::FrameworkFlags,
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
     loader::LINTABLE_EXTENSIONS,
     module_record::ModuleRecord,
     options::LintOptions,
-    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
-    service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind, ParseMode},
+    rule::{
+        CustomRule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner,
+    },
+    service::{FileResult, LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
+    source_map::RemappedLocation,
     suppression::{OxlintSuppressionFileAction, SuppressionManager},
     timing::{RuleTimingRecord, RuleTimingSource, RuleTimingStore},
     tsgolint::TsGoLintState,
     utils::{read_to_arena_str, read_to_string},
 };
 use crate::{
-    config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings},
+    config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, canonical_rule_name},
     context::ContextHost,
     external_linter::GlobalsAndEnvs,
     fixer::CompositeFix,
@@ -167,6 +176,8 @@ struct RuleBuckets {
     by_type: Box<[Vec<usize>; AST_TYPE_MAX as usize + 1]>,
     /// Indices of rules that run on every node (rules without `types_info`).
     any_type: Vec<usize>,
+    /// Indices of rules that implement `run_once` and also visit nodes, so `run_once` runs last.
+    run_once: Vec<usize>,
 }
 
 impl RuleBuckets {
@@ -175,6 +186,7 @@ impl RuleBuckets {
             bucket.clear();
         }
         self.any_type.clear();
+        self.run_once.clear();
     }
 }
 
@@ -182,6 +194,7 @@ thread_local! {
     static RULE_BUCKETS: std::cell::RefCell<RuleBuckets> = std::cell::RefCell::new(RuleBuckets {
         by_type: boxed_array![Vec::new(); AST_TYPE_MAX as usize + 1],
         any_type: Vec::new(),
+        run_once: Vec::new(),
     });
 }
 
@@ -215,9 +228,13 @@ fn execute_rules<'a, const TIMINGS: bool>(
                     buckets.any_type.push(rule_index);
                 }
 
-                if run_info.is_run_once_implemented() {
+                // Rules which only implement `run_once` run first, so their diagnostics come first.
+                // Other rules run it after visiting nodes, so it can use what they saw there.
+                if run_info == RuleRunFunctionsImplemented::RunOnce {
                     let timing_stat = get_timing_stat::<TIMINGS>(&mut timing_stats, rule_index);
                     rule.run_once::<TIMINGS>(ctx, timing_stat);
+                } else if run_info.is_run_once_implemented() {
+                    buckets.run_once.push(rule_index);
                 }
             }
 
@@ -245,14 +262,17 @@ fn execute_rules<'a, const TIMINGS: bool>(
                     }
                 }
             }
+
+            for &rule_index in &buckets.run_once {
+                let (rule, ctx) = &rules[rule_index];
+                let timing_stat = get_timing_stat::<TIMINGS>(&mut timing_stats, rule_index);
+                rule.run_once::<TIMINGS>(ctx, timing_stat);
+            }
         });
     } else {
         // Unoptimized reference path: every rule runs on every node, with no type filtering. Used
         // only in debug builds, to assert the optimized path produces identical diagnostics.
         for (rule_index, (rule, ctx)) in rules.iter().enumerate() {
-            let timing_stat = get_timing_stat::<TIMINGS>(&mut timing_stats, rule_index);
-            rule.run_once::<TIMINGS>(ctx, timing_stat);
-
             for node in semantic.nodes() {
                 let timing_stat = get_timing_stat::<TIMINGS>(&mut timing_stats, rule_index);
                 rule.run::<TIMINGS>(node, ctx, timing_stat);
@@ -264,6 +284,9 @@ fn execute_rules<'a, const TIMINGS: bool>(
                     rule.run_on_jest_node::<TIMINGS>(&jest_node, ctx, timing_stat);
                 }
             }
+
+            let timing_stat = get_timing_stat::<TIMINGS>(&mut timing_stats, rule_index);
+            rule.run_once::<TIMINGS>(ctx, timing_stat);
         }
     }
 
@@ -279,6 +302,15 @@ fn execute_rules<'a, const TIMINGS: bool>(
 /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
 const WEBSITE_BASE_RULES_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
 
+/// Options for parsing files to be linted.
+pub(crate) fn parse_options() -> ParseOptions {
+    ParseOptions {
+        parse_regular_expression: true,
+        allow_return_outside_function: true,
+        ..ParseOptions::default()
+    }
+}
+
 #[derive(Debug)]
 #[expect(clippy::struct_field_names)]
 pub struct Linter {
@@ -286,6 +318,14 @@ pub struct Linter {
     config: ConfigStore,
     external_linter: Option<ExternalLinter>,
     workspace_uri: Option<Box<str>>,
+    categories: Option<Box<[RuleCategory]>>,
+    /// `(plugin_name, rule_name)` of rules whose fixes are never applied.
+    unfixable_rules: Vec<(String, String)>,
+    max_file_size: Option<u32>,
+    max_node_count: Option<u32>,
+    custom_rules: Vec<(Box<dyn CustomRule>, AllowWarnDeny)>,
+    /// See [`ConfigStore::ignore_comment_prefixes`]. Shared by all files, instead of cloned for each.
+    ignore_comment_prefixes: Arc<[String]>,
 }
 
 impl Linter {
@@ -294,7 +334,21 @@ impl Linter {
         config: ConfigStore,
         external_linter: Option<ExternalLinter>,
     ) -> Self {
-        Self { options, config, external_linter, workspace_uri: None }
+        let ignore_comment_prefixes = config.ignore_comment_prefixes().into();
+        let unfixable_rules =
+            config.unfixable_rules().iter().map(|name| canonical_rule_name(name)).collect();
+        Self {
+            options,
+            config,
+            external_linter,
+            workspace_uri: None,
+            categories: None,
+            unfixable_rules,
+            max_file_size: None,
+            max_node_count: None,
+            custom_rules: Vec::new(),
+            ignore_comment_prefixes,
+        }
     }
 
     #[must_use]
@@ -310,6 +364,64 @@ impl Linter {
         self
     }
 
+    /// Only run enabled rules that belong to one of `categories`.
+    ///
+    /// Rules are still enabled and configured as usual; this only narrows which of them are
+    /// dispatched, e.g. to run just `correctness` rules in a fast CI job.
+    #[must_use]
+    pub fn with_categories(mut self, categories: &[RuleCategory]) -> Self {
+        self.categories = Some(categories.into());
+        self
+    }
+
+    /// Never fix diagnostics reported by `rules`, even when they provide a fix.
+    ///
+    /// Rule names are in the same format as keys of `rules` in config, e.g. `no-debugger`,
+    /// `eslint/no-debugger` or `unicorn/no-null`. Diagnostics from these rules are still reported.
+    ///
+    /// `rules` are added to the rules in [`ConfigStore::unfixable_rules`].
+    #[must_use]
+    pub fn with_unfixable_rules<S: AsRef<str>>(
+        mut self,
+        rules: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.unfixable_rules
+            .extend(rules.into_iter().map(|name| canonical_rule_name(name.as_ref())));
+        self
+    }
+
+    /// Don't run any rules on files whose source text is longer than `max_bytes` bytes.
+    ///
+    /// A single informational diagnostic is reported for such files instead. For files with
+    /// multiple script blocks (e.g. `.vue`), the limit applies to each block separately.
+    #[must_use]
+    pub fn with_max_file_size(mut self, max_bytes: u32) -> Self {
+        self.max_file_size = Some(max_bytes);
+        self
+    }
+
+    /// Don't run any rules on files whose AST has more than `max_nodes` nodes.
+    ///
+    /// Behaves the same as [`Linter::with_max_file_size`] otherwise.
+    #[must_use]
+    pub fn with_max_node_count(mut self, max_nodes: u32) -> Self {
+        self.max_node_count = Some(max_nodes);
+        self
+    }
+
+    /// Run `rules` on every file, in addition to the rules enabled by the config.
+    ///
+    /// This allows other crates to provide their own rules, by implementing [`CustomRule`].
+    /// Rules with severity [`AllowWarnDeny::Allow`] are ignored.
+    #[must_use]
+    pub fn with_custom_rules(
+        mut self,
+        rules: impl IntoIterator<Item = (Box<dyn CustomRule>, AllowWarnDeny)>,
+    ) -> Self {
+        self.custom_rules.extend(rules.into_iter().filter(|(_, severity)| severity.is_warn_deny()));
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
@@ -324,11 +436,17 @@ impl Linter {
         self.config.respect_eslint_disable_directives()
     }
 
+    pub(crate) fn ignore_comment_prefixes(&self) -> &Arc<[String]> {
+        &self.ignore_comment_prefixes
+    }
+
     /// Returns the number of rules that will are being used, unless there
     /// nested configurations in use, in which case it returns `None` since the
     /// number of rules depends on which file is being linted.
     pub fn number_of_rules(&self, type_aware: bool) -> Option<usize> {
-        self.config.number_of_rules(type_aware)
+        self.config
+            .number_of_rules(type_aware)
+            .map(|count| count + self.custom_rules_in_categories().count())
     }
 
     /// Return `true` if `Linter` has an external linter (JS plugins).
@@ -347,6 +465,135 @@ impl Linter {
         self.run_with_disable_directives::<false>(path, context_sub_hosts, allocator, None, None).0
     }
 
+    /// Parse `source_text` according to `parse_mode`, and run rules over it.
+    ///
+    /// In [`ParseMode::Expression`] mode, the expression is wrapped in an expression statement,
+    /// so rules see a program containing that single statement.
+    ///
+    /// # Errors
+    /// Returns syntax errors if `source_text` could not be parsed.
+    pub fn run_source<'a>(
+        &self,
+        path: &Path,
+        source_text: &'a str,
+        source_type: SourceType,
+        parse_mode: ParseMode,
+        allocator: &'a Allocator,
+    ) -> Result<Vec<Message>, Vec<OxcDiagnostic>> {
+        let parser = Parser::new(allocator, source_text, source_type).with_options(parse_options());
+        let (program, parser_module_record) = match parse_mode {
+            ParseMode::Program => {
+                let ret = parser.parse();
+                if !ret.diagnostics.is_empty() {
+                    return Err(ret.diagnostics.into());
+                }
+                (ret.program, Some(ret.module_record))
+            }
+            ParseMode::Expression => {
+                let (expression, comments) =
+                    parser.parse_expression_with_comments().map_err(Vec::from)?;
+                let builder = AstBuilder::new(allocator);
+                let span = Span::new(0, u32::try_from(source_text.len()).unwrap_or(u32::MAX));
+                let statement =
+                    Statement::new_expression_statement(expression.span(), expression, &builder);
+                let program = Program::new(
+                    span,
+                    source_type,
+                    source_text,
+                    comments,
+                    None,
+                    ArenaVec::new_in(&builder),
+                    ArenaVec::from_value_in(statement, &builder),
+                    &builder,
+                );
+                (program, None)
+            }
+        };
+
+        let semantic_ret = SemanticBuilder::new_linter().build(allocator.alloc(program));
+        if !semantic_ret.diagnostics.is_empty() {
+            return Err(semantic_ret.diagnostics.into());
+        }
+
+        let semantic = semantic_ret.semantic;
+        let module_record = parser_module_record.map_or_else(ModuleRecord::default, |record| {
+            ModuleRecord::new(path, &record, &semantic)
+        });
+        let sub_host = ContextSubHost::new(
+            semantic,
+            Arc::new(module_record),
+            0,
+            ContextSubHostOptions::default(),
+        );
+        Ok(self.run(path, vec![sub_host], allocator))
+    }
+
+    /// Run rules like [`Linter::run`], but only record which rules reported a diagnostic, and where.
+    ///
+    /// Fixes are not computed, and no [`Message`]s are created, so this is cheaper than
+    /// [`Linter::run`] when only e.g. counts of rule violations are needed.
+    /// Unused disable directives are not reported.
+    pub fn run_analysis<'a>(
+        &self,
+        path: &Path,
+        context_sub_hosts: Vec<ContextSubHost<'a>>,
+        allocator: &'a Allocator,
+    ) -> Vec<RuleMatch> {
+        let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
+        let ctx_host = ContextHost::new(path, context_sub_hosts, self.options, config);
+        let mut ctx_host = Rc::new(ctx_host.with_analysis_only());
+        let is_partial_loader_file = ctx_host
+            .file_extension()
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext));
+
+        self.run_rules::<false>(
+            path,
+            &mut ctx_host,
+            &rules,
+            &external_rules,
+            allocator,
+            None,
+            None,
+            is_partial_loader_file,
+        );
+
+        ctx_host.take_rule_matches()
+    }
+
+    /// Run rules like [`Linter::run`], but pass each diagnostic to `callback` as soon as it is
+    /// reported, instead of collecting them all and returning them at the end.
+    ///
+    /// Diagnostics suppressed by disable directives are not passed to `callback`.
+    pub fn run_with_callback<'a, 'c: 'a>(
+        &'c self,
+        path: &Path,
+        context_sub_hosts: Vec<ContextSubHost<'a>>,
+        allocator: &'a Allocator,
+        callback: &'c mut dyn FnMut(Message),
+    ) {
+        let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
+        let callback = Box::new(move |mut message: Message| {
+            self.remove_unfixable_fixes(&mut message);
+            callback(message);
+        });
+        let ctx_host = ContextHost::new(path, context_sub_hosts, self.options, config);
+        let mut ctx_host = Rc::new(ctx_host.with_diagnostic_callback(callback));
+        let is_partial_loader_file = ctx_host
+            .file_extension()
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext));
+
+        self.run_rules::<false>(
+            path,
+            &mut ctx_host,
+            &rules,
+            &external_rules,
+            allocator,
+            None,
+            None,
+            is_partial_loader_file,
+        );
+    }
+
     /// Same as `run` but also returns the disable directives for the file
     ///
     /// # Parameters
@@ -369,15 +616,114 @@ impl Linter {
 
         let mut ctx_host = Rc::new(ContextHost::new(path, context_sub_hosts, self.options, config));
 
-        #[cfg(debug_assertions)]
-        let mut current_diagnostic_index = 0;
-
         let is_partial_loader_file = ctx_host
             .file_extension()
             .is_some_and(|ext| LINT_PARTIAL_LOADER_EXTENSIONS.iter().any(|e| e == &ext));
 
+        let skipped = self.run_rules::<TIMINGS>(
+            path,
+            &mut ctx_host,
+            &rules,
+            &external_rules,
+            allocator,
+            js_allocator_pool,
+            timing_recorder.as_mut(),
+            is_partial_loader_file,
+        );
+
+        let mut diagnostics = ctx_host.take_diagnostics();
+        if !self.unfixable_rules.is_empty() {
+            for message in &mut diagnostics {
+                self.remove_unfixable_fixes(message);
+            }
+        }
+        // Disable directives in skipped files would all be reported as unused.
+        let disable_directives = if is_partial_loader_file || skipped {
+            None
+        } else {
+            Rc::try_unwrap(ctx_host).unwrap().into_disable_directives()
+        };
+
+        let result = (diagnostics, disable_directives);
+        if TIMINGS {
+            let timing_recorder = timing_recorder.expect("missing rule timing recorder");
+            rule_timing_store.expect("missing rule timing store").merge(
+                timing_recorder.into_timings().into_iter().map(|(key, stat)| RuleTimingRecord {
+                    source: key.source,
+                    plugin_name: key.plugin_name.into_owned(),
+                    rule_name: key.rule_name.into_owned(),
+                    duration: stat.duration,
+                    calls: stat.calls,
+                }),
+            );
+        }
+        result
+    }
+
+    /// Remove fixes from `message` if it was reported by a rule which is configured as unfixable.
+    fn remove_unfixable_fixes(&self, message: &mut Message) {
+        if let Some(rule) = &message.rule
+            && self.unfixable_rules.iter().any(|(plugin_name, rule_name)| {
+                *plugin_name == rule.plugin_name && *rule_name == rule.rule_name
+            })
+        {
+            message.fixes = PossibleFixes::None;
+        }
+    }
+
+    /// Returns a diagnostic if `semantic` exceeds the configured file size or node count limits.
+    fn check_size_limits(&self, semantic: &Semantic) -> Option<OxcDiagnostic> {
+        let reason = if let Some(max_bytes) = self.max_file_size
+            && semantic.source_text().len() > max_bytes as usize
+        {
+            format!("it is larger than {max_bytes} bytes")
+        } else if let Some(max_nodes) = self.max_node_count
+            && semantic.nodes().len() > max_nodes as usize
+        {
+            format!("it has more than {max_nodes} AST nodes")
+        } else {
+            return None;
+        };
+        Some(
+            OxcDiagnostic::warn(format!("File was not linted because {reason}"))
+                .with_severity(Severity::Advice),
+        )
+    }
+
+    /// Run `rules` and `external_rules` on each sub host of `ctx_host`.
+    ///
+    /// Returns `true` if any sub host was skipped because it exceeded the size limits.
+    #[expect(clippy::too_many_arguments)]
+    fn run_rules<'a, const TIMINGS: bool>(
+        &self,
+        path: &Path,
+        ctx_host: &mut Rc<ContextHost<'a>>,
+        rules: &[(RuleEnum, AllowWarnDeny)],
+        external_rules: &[(ExternalRuleId, ExternalOptionsId, AllowWarnDeny)],
+        allocator: &'a Allocator,
+        js_allocator_pool: Option<&AllocatorPool>,
+        mut timing_recorder: Option<&mut RuleTimingRecorder>,
+        is_partial_loader_file: bool,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        let mut current_diagnostic_index = 0;
+        let mut skipped = false;
+
         loop {
             let semantic = ctx_host.semantic();
+
+            if let Some(diagnostic) = self.check_size_limits(semantic) {
+                skipped = true;
+                ctx_host.push_diagnostic(Message::new(diagnostic, PossibleFixes::None));
+                if !ctx_host.next_sub_host() {
+                    break;
+                }
+                #[cfg(debug_assertions)]
+                {
+                    current_diagnostic_index = ctx_host.diagnostic_count();
+                }
+                continue;
+            }
             let rules = rules
                 .iter()
                 .filter(|(rule, _)| {
@@ -385,6 +731,12 @@ impl Linter {
                         return false;
                     }
 
+                    if let Some(categories) = &self.categories
+                        && !categories.contains(&rule.category())
+                    {
+                        return false;
+                    }
+
                     // If only the `run` function is implemented, we can skip running the file entirely if the current
                     // file does not contain any of the relevant AST node types.
                     if rule.run_info() == RuleRunFunctionsImplemented::Run
@@ -394,25 +746,29 @@ impl Linter {
                         return false;
                     }
 
-                    rule.should_run(&ctx_host)
+                    rule.should_run(ctx_host)
                 })
-                .map(|(rule, severity)| (rule, Rc::clone(&ctx_host).spawn(rule, *severity)))
+                .map(|(rule, severity)| (rule, Rc::clone(ctx_host).spawn(rule, *severity)))
                 .collect::<Vec<_>>();
 
             let should_run_on_jest_node =
                 ctx_host.plugins().has_test() && ctx_host.frameworks().is_test();
 
+            ctx_host.clear_rule_states();
             execute_rules::<TIMINGS>(
                 &rules,
                 semantic,
                 should_run_on_jest_node,
                 true,
-                timing_recorder.as_mut(),
+                timing_recorder.as_deref_mut(),
             );
 
+            // Rule matches are not checked, as they are not comparable across runs.
+            // Diagnostics passed to a callback can't be checked, as they are not collected.
             #[cfg(debug_assertions)]
-            {
+            if !ctx_host.is_analysis_only() && !ctx_host.has_diagnostic_callback() {
                 let diagnostics_after_optimized = ctx_host.diagnostic_count();
+                ctx_host.clear_rule_states();
                 execute_rules::<false>(&rules, semantic, should_run_on_jest_node, false, None);
                 let diagnostics_after_unoptimized = ctx_host.diagnostic_count();
                 ctx_host.get_diagnostics(|diagnostics| {
@@ -454,23 +810,20 @@ impl Linter {
                 });
             }
 
+            self.run_custom_rules(ctx_host);
+
             // Drop `rules` to release its `Rc` clones of `ctx_host`, ensuring `run_external_rules`
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
-            self.run_external_rules(
-                &external_rules,
-                path,
-                &mut ctx_host,
-                allocator,
-                js_allocator_pool,
-            );
+            self.run_external_rules(external_rules, path, ctx_host, allocator, js_allocator_pool);
 
             // Report unused directives is now handled differently with type-aware linting
 
             if let Some(severity) = self.options.report_unused_directive
                 && severity.is_warn_deny()
                 && is_partial_loader_file
+                && !ctx_host.is_analysis_only()
             {
                 ctx_host.report_unused_directives(severity.into());
             }
@@ -486,27 +839,39 @@ impl Linter {
             }
         }
 
-        let diagnostics = ctx_host.take_diagnostics();
-        let disable_directives = if is_partial_loader_file {
-            None
-        } else {
-            Rc::try_unwrap(ctx_host).unwrap().into_disable_directives()
-        };
+        skipped
+    }
 
-        let result = (diagnostics, disable_directives);
-        if TIMINGS {
-            let timing_recorder = timing_recorder.expect("missing rule timing recorder");
-            rule_timing_store.expect("missing rule timing store").merge(
-                timing_recorder.into_timings().into_iter().map(|(key, stat)| RuleTimingRecord {
-                    source: key.source,
-                    plugin_name: key.plugin_name.into_owned(),
-                    rule_name: key.rule_name.into_owned(),
-                    duration: stat.duration,
-                    calls: stat.calls,
-                }),
-            );
+    /// Custom rules that belong to one of the categories set with [`Linter::with_categories`].
+    fn custom_rules_in_categories(
+        &self,
+    ) -> impl Iterator<Item = &(Box<dyn CustomRule>, AllowWarnDeny)> {
+        self.custom_rules.iter().filter(|(rule, _)| {
+            self.categories.as_ref().is_none_or(|categories| categories.contains(&rule.category()))
+        })
+    }
+
+    /// Run rules registered with [`Linter::with_custom_rules`] on the current sub host of `ctx_host`.
+    fn run_custom_rules(&self, ctx_host: &Rc<ContextHost<'_>>) {
+        if self.custom_rules.is_empty() {
+            return;
+        }
+
+        let rules = self
+            .custom_rules_in_categories()
+            .map(|(rule, severity)| {
+                (rule, Rc::clone(ctx_host).spawn_custom(rule.as_ref(), *severity))
+            })
+            .collect::<Vec<_>>();
+
+        for node in ctx_host.semantic().nodes() {
+            for (rule, ctx) in &rules {
+                rule.run(node, ctx);
+            }
+        }
+        for (rule, ctx) in &rules {
+            rule.run_once(ctx);
         }
-        result
     }
 
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
@@ -953,3 +1318,328 @@ impl RawTransferMetadata {
         Self { data_offset, is_ts, is_jsx, has_bom, tokens_offset, tokens_len }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_diagnostics::{OxcDiagnostic, Severity};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{
+        AllowWarnDeny, AstNode, ConfigStore, ConfigStoreBuilder, ContextSubHost,
+        ContextSubHostOptions, CustomRule, ExternalPluginStore, FixKind, LintContext, LintOptions,
+        Linter, Message, ModuleRecord, ParseMode, RuleCategory,
+        rules::{EslintCurly, EslintNoDebugger, EslintNoMultiAssign, RuleEnum},
+    };
+
+    /// A linter with `rules` enabled as warnings.
+    fn linter(rules: impl IntoIterator<Item = RuleEnum>) -> Linter {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = rules
+            .into_iter()
+            .fold(ConfigStoreBuilder::empty(), |builder, rule| {
+                builder.with_rule(rule, AllowWarnDeny::Warn)
+            })
+            .build(&mut external_plugin_store)
+            .unwrap();
+        Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+    }
+
+    fn run(source: &str, configure: impl FnOnce(Linter) -> Linter) -> Vec<Message> {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        let program = allocator.alloc(parser_ret.program);
+        let semantic = SemanticBuilder::new_linter().build(program).semantic;
+
+        let linter = configure(linter([
+            RuleEnum::EslintNoDebugger(EslintNoDebugger),
+            RuleEnum::EslintCurly(EslintCurly::default()),
+        ]));
+
+        let sub_host = ContextSubHost::new(
+            semantic,
+            Arc::new(ModuleRecord::default()),
+            0,
+            ContextSubHostOptions::default(),
+        );
+        linter.run(Path::new("test.js"), vec![sub_host], &allocator)
+    }
+
+    fn lint(source: &str, categories: Option<&[RuleCategory]>) -> Vec<String> {
+        let mut rules = run(source, |linter| match categories {
+            Some(categories) => linter.with_categories(categories),
+            None => linter,
+        })
+        .into_iter()
+        .map(|message| message.rule.unwrap().rule_name.into_owned())
+        .collect::<Vec<_>>();
+        rules.sort_unstable();
+        rules
+    }
+
+    #[test]
+    fn test_with_categories() {
+        let source = "if (foo) debugger;";
+
+        assert_eq!(lint(source, None), vec!["curly", "no-debugger"]);
+        // `curly` is a `style` rule, so it is skipped.
+        assert_eq!(lint(source, Some(&[RuleCategory::Correctness])), vec!["no-debugger"]);
+        assert_eq!(
+            lint(source, Some(&[RuleCategory::Correctness, RuleCategory::Style])),
+            vec!["curly", "no-debugger"]
+        );
+        assert!(lint(source, Some(&[RuleCategory::Pedantic])).is_empty());
+    }
+
+    #[test]
+    fn test_with_unfixable_rules() {
+        let source = "debugger;";
+
+        let messages = run(source, |linter| linter.with_fix(FixKind::All));
+        assert_eq!(messages.len(), 1);
+        assert!(!messages[0].fixes.is_empty());
+
+        // `no-debugger` is fixable, but its fix is dropped. The diagnostic is still reported.
+        let messages = run(source, |linter| {
+            linter.with_fix(FixKind::All).with_unfixable_rules(["no-debugger"])
+        });
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].rule.as_ref().unwrap().rule_name, "no-debugger");
+        assert!(messages[0].fixes.is_empty());
+
+        // Names are accepted in any format allowed in `rules`.
+        let messages = run(source, |linter| {
+            linter.with_fix(FixKind::All).with_unfixable_rules(["eslint/no-debugger"])
+        });
+        assert!(messages[0].fixes.is_empty());
+    }
+
+    #[test]
+    fn test_unfixable_rules_from_config() {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, "debugger;", SourceType::default()).parse();
+        let semantic =
+            SemanticBuilder::new_linter().build(allocator.alloc(parser_ret.program)).semantic;
+
+        let oxlintrc = serde_json::from_str(
+            r#"{
+                "rules": { "no-debugger": "warn" },
+                "options": { "unfixableRules": ["eslint/no-debugger"] }
+            }"#,
+        )
+        .unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut external_plugin_store,
+            None,
+        )
+        .unwrap()
+        .build(&mut external_plugin_store)
+        .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+        .with_fix(FixKind::All);
+
+        let sub_host = ContextSubHost::new(
+            semantic,
+            Arc::new(ModuleRecord::default()),
+            0,
+            ContextSubHostOptions::default(),
+        );
+        let messages = linter.run(Path::new("test.js"), vec![sub_host], &allocator);
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].fixes.is_empty());
+    }
+
+    #[derive(Debug)]
+    struct NoFoo;
+
+    impl CustomRule for NoFoo {
+        fn plugin_name(&self) -> &'static str {
+            "custom"
+        }
+
+        fn name(&self) -> &'static str {
+            "no-foo"
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            if let AstKind::IdentifierReference(ident) = node.kind()
+                && ident.name == "foo"
+            {
+                ctx.diagnostic(OxcDiagnostic::warn("Unexpected `foo`").with_label(ident.span));
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_custom_rules() {
+        let source = "if (foo) debugger;";
+        let custom_rules = || [(Box::new(NoFoo) as Box<dyn CustomRule>, AllowWarnDeny::Deny)];
+
+        let messages = run(source, |linter| linter.with_custom_rules(custom_rules()));
+        let mut rules = messages
+            .iter()
+            .map(|message| message.rule.as_ref().unwrap().short_canonical_name())
+            .collect::<Vec<_>>();
+        rules.sort_unstable();
+        assert_eq!(rules, vec!["curly", "custom/no-foo", "no-debugger"]);
+
+        let message = messages
+            .iter()
+            .find(|message| message.rule.as_ref().unwrap().rule_name == "no-foo")
+            .unwrap();
+        assert_eq!(message.error.severity, Severity::Error);
+        assert_eq!(message.span, Span::new(4, 7));
+
+        // Custom rules are filtered by category like any other rule.
+        let messages = run(source, |linter| {
+            linter.with_custom_rules(custom_rules()).with_categories(&[RuleCategory::Style])
+        });
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].rule.as_ref().unwrap().rule_name, "curly");
+
+        let number_of_rules = |categories: &[RuleCategory]| {
+            linter([])
+                .with_custom_rules(custom_rules())
+                .with_categories(categories)
+                .number_of_rules(false)
+        };
+        assert_eq!(number_of_rules(&[RuleCategory::Correctness]), Some(1));
+        assert_eq!(number_of_rules(&[RuleCategory::Style]), Some(0));
+
+        let messages = run(source, |linter| {
+            linter
+                .with_custom_rules([(Box::new(NoFoo) as Box<dyn CustomRule>, AllowWarnDeny::Allow)])
+        });
+        assert_eq!(messages.len(), 2);
+    }
+
+    /// Counts identifier references in `run`, and reports names used more than once in `run_once`.
+    #[derive(Debug)]
+    struct NoDuplicateReferences;
+
+    impl CustomRule for NoDuplicateReferences {
+        fn plugin_name(&self) -> &'static str {
+            "custom"
+        }
+
+        fn name(&self) -> &'static str {
+            "no-duplicate-references"
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            if let AstKind::IdentifierReference(ident) = node.kind() {
+                let mut counts = ctx.rule_state::<FxHashMap<String, usize>>();
+                *counts.entry(ident.name.to_string()).or_default() += 1;
+            }
+        }
+
+        fn run_once(&self, ctx: &LintContext) {
+            let counts = ctx.rule_state::<FxHashMap<String, usize>>();
+            let mut duplicates = counts.iter().filter(|(_, count)| **count > 1).collect::<Vec<_>>();
+            duplicates.sort_unstable();
+            for (name, count) in duplicates {
+                ctx.diagnostic(OxcDiagnostic::warn(format!(
+                    "`{name}` is referenced {count} times"
+                )));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_state() {
+        let custom_rules =
+            || [(Box::new(NoDuplicateReferences) as Box<dyn CustomRule>, AllowWarnDeny::Warn)];
+        let duplicates = |source: &str| {
+            run(source, |linter| linter.with_custom_rules(custom_rules()))
+                .into_iter()
+                .filter(|message| message.rule.as_ref().unwrap().rule_name != "curly")
+                .map(|message| message.error.message.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            duplicates("a; b; a; c; b; a;"),
+            ["`a` is referenced 3 times", "`b` is referenced 2 times"]
+        );
+        assert!(duplicates("a; b; c;").is_empty());
+    }
+
+    #[test]
+    fn test_with_size_limits() {
+        let source = "if (foo) debugger;";
+
+        // Limits which are not exceeded have no effect.
+        let messages = run(source, |linter| linter.with_max_file_size(18).with_max_node_count(100));
+        assert_eq!(messages.len(), 2);
+
+        // No rules are run, only a single informational diagnostic is reported.
+        let messages = run(source, |linter| linter.with_max_file_size(8));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].rule.is_none());
+        assert_eq!(messages[0].error.severity, Severity::Advice);
+        assert_eq!(
+            messages[0].error.message,
+            "File was not linted because it is larger than 8 bytes"
+        );
+
+        let messages = run(source, |linter| linter.with_max_node_count(2));
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].rule.is_none());
+        assert_eq!(
+            messages[0].error.message,
+            "File was not linted because it has more than 2 AST nodes"
+        );
+    }
+
+    #[test]
+    fn test_run_source_expression() {
+        let allocator = Allocator::default();
+        let linter = linter([RuleEnum::EslintNoMultiAssign(EslintNoMultiAssign::default())]);
+        let lint = |source_text, parse_mode| {
+            linter.run_source(
+                Path::new("test.js"),
+                source_text,
+                SourceType::default(),
+                parse_mode,
+                &allocator,
+            )
+        };
+
+        let messages = lint("a = b = c", ParseMode::Expression).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].rule.as_ref().unwrap().rule_name, "no-multi-assign");
+        assert_eq!(messages[0].span, Span::new(4, 9));
+
+        let messages = lint("a = b", ParseMode::Expression).unwrap();
+        assert!(messages.is_empty());
+
+        // Comments are kept, so disable directives apply
+        let messages =
+            lint("/* eslint-disable no-multi-assign */ a = b = c", ParseMode::Expression).unwrap();
+        assert!(messages.is_empty());
+
+        // Statements are not expressions
+        assert!(lint("let a = b = c;", ParseMode::Expression).is_err());
+        let messages = lint("let a = b = c;", ParseMode::Program).unwrap();
+        assert_eq!(messages.len(), 1);
+    }
+}
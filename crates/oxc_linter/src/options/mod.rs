@@ -14,3 +14,13 @@ pub struct LintOptions {
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
 }
+
+/// How source text passed to [`Linter::run_source`](crate::Linter::run_source) is parsed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Parse source text as a full program.
+    #[default]
+    Program,
+    /// Parse source text as a single expression, e.g. an expression inside a template.
+    Expression,
+}
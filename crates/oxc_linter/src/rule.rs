@@ -45,6 +45,10 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {}
 
     /// Run only once. Useful for inspecting scopes and trivias etc.
+    ///
+    /// For rules which also implement `run` or `run_on_jest_node`, this runs after they have
+    /// visited the whole file, so it can report based on state gathered there with
+    /// [`LintContext::rule_state`].
     #[expect(unused_variables)]
     #[inline]
     fn run_once(&self, ctx: &LintContext) {}
@@ -75,6 +79,37 @@ pub trait Rule: Sized + Default + fmt::Debug {
     }
 }
 
+/// A lint rule defined outside of this crate, registered with [`Linter::with_custom_rules`].
+///
+/// Unlike [`Rule`], this trait is object safe, so rules from other crates can be passed to the
+/// linter as `Box<dyn CustomRule>`. Custom rules are not configurable via `.oxlintrc.json`.
+///
+/// [`Linter::with_custom_rules`]: crate::Linter::with_custom_rules
+pub trait CustomRule: fmt::Debug + Send + Sync {
+    /// Name of the plugin this rule belongs to. Example: `my-plugin`
+    fn plugin_name(&self) -> &'static str;
+
+    /// Kebab-cased name of the rule. Example: `no-foo`
+    fn name(&self) -> &'static str;
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    /// Kinds of fixes this rule can provide.
+    fn fix(&self) -> RuleFixMeta {
+        RuleFixMeta::None
+    }
+
+    /// Visit each AST Node. See [`Rule::run`].
+    #[expect(unused_variables)]
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {}
+
+    /// Run once per file, after `run` has visited the whole file. See [`Rule::run_once`].
+    #[expect(unused_variables)]
+    fn run_once(&self, ctx: &LintContext) {}
+}
+
 /// Pretty-print a JSON value and collapse all whitespace runs to single spaces,
 /// for embedding a "received config" snippet in a rule-config deserialization error.
 ///
@@ -1,10 +1,12 @@
 use oxc_ast::{
     AstKind,
     ast::{
-        ChainElement, Expression, MemberExpression, MethodDefinitionKind, ObjectProperty,
-        PropertyKind, match_member_expression,
+        ArrowFunctionExpression, ChainElement, Expression, Function, MemberExpression,
+        MethodDefinitionKind, ObjectProperty, PropertyKind, ReturnStatement,
+        match_member_expression,
     },
 };
+use oxc_ast_visit::Visit;
 use oxc_cfg::{
     EdgeType, ErrorEdgeKind, InstructionKind, ReturnInstructionKind,
     graph::{
@@ -14,6 +16,7 @@ use oxc_cfg::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
 use oxc_span::Span;
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -26,6 +29,12 @@ use crate::{
 };
 
 fn getter_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected to return a value in getter.")
+        .with_help("Return a value from all code paths in getter.")
+        .with_label(span)
+}
+
+fn getter_always_return_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected to always return a value in getter.")
         .with_help("Return a value from all code paths in getter.")
         .with_label(span)
@@ -303,12 +312,47 @@ impl GetterReturn {
             output.break_value().is_none()
         };
 
-        if !definitely_returns_in_all_codepaths {
+        if definitely_returns_in_all_codepaths {
+            return;
+        }
+
+        let mut finder = ReturnValueFinder { allow_implicit: self.allow_implicit, found: false };
+        match node.kind() {
+            AstKind::Function(func) => {
+                if let Some(body) = &func.body {
+                    finder.visit_function_body(body);
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) => finder.visit_function_body(&arrow.body),
+            _ => {}
+        }
+
+        if finder.found {
+            ctx.diagnostic(getter_always_return_diagnostic(span));
+        } else {
             ctx.diagnostic(getter_return_diagnostic(span));
         }
     }
 }
 
+/// Finds a `return` statement which returns a value from the getter, ignoring nested functions.
+struct ReturnValueFinder {
+    allow_implicit: bool,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ReturnValueFinder {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        if it.argument.is_some() || self.allow_implicit {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -427,6 +471,16 @@ fn test() {
         ("var foo = { get bar(){if(baz) {return true;}} };", None),
         ("var foo = { get bar() { ~function () {return true;}} };", None),
         ("var foo = { get bar() { return; } };", None),
+        ("var foo = { get bar() { if (baz) { return; } return true; } };", None),
+        (
+            "var foo = { get bar() { if (baz) { return true; } else if (qux) { throw new Error(); } } };",
+            None,
+        ),
+        (
+            "var foo = { get bar() { switch (baz) { case 1: return true; default: break; } } };",
+            None,
+        ),
+        ("class foo { get bar() { const fn = () => { return true; }; } }", None),
         ("var foo = { get bar() {} };", Some(serde_json::json!([{ "allowImplicit": true }]))),
         (
             "var foo = { get bar() {if (baz) {return;}} };",
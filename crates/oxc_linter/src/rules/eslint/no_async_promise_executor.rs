@@ -90,7 +90,10 @@ impl Rule for NoAsyncPromiseExecutor {
         let AstKind::NewExpression(new_expression) = node.kind() else {
             return;
         };
-        if !new_expression.callee.is_specific_id("Promise") {
+        let Expression::Identifier(ident) = &new_expression.callee else {
+            return;
+        };
+        if ident.name != "Promise" || !ctx.is_reference_to_global_variable(ident) {
             return;
         }
         let Some(expression) = new_expression.arguments.first().and_then(Argument::as_expression)
@@ -117,12 +120,21 @@ fn test() {
         "new Promise((resolve, reject) => {})",
         "new Promise((resolve, reject) => {}, async function unrelated() {})",
         "new Foo(async (resolve, reject) => {})",
+        "new Promise(function (resolve, reject) {})",
+        "new Promise(async)",
+        "new Promise()",
+        "Promise(async () => {})",
+        "class Promise {} new Promise(async () => {})",
+        "function foo(Promise) { new Promise(async function () {}) }",
     ];
 
     let fail = vec![
         "new Promise(async function foo(resolve, reject) {})",
         "new Promise(async (resolve, reject) => {})",
         "new Promise(((((async () => {})))))",
+        "new Promise(async function (resolve, reject) {}, foo)",
+        "new Promise(async () => await foo)",
+        "function foo() { new Promise(async (resolve) => resolve(await bar)) }",
     ];
 
     Tester::new(NoAsyncPromiseExecutor::NAME, NoAsyncPromiseExecutor::PLUGIN, pass, fail)
@@ -69,7 +69,7 @@ declare_oxc_lint!(
     /// ```
     NoCaseDeclarations,
     eslint,
-    pedantic,
+    correctness,
     suggestion,
     version = "0.0.4",
     short_description = "Disallow lexical declarations in case clauses.",
@@ -84,8 +84,12 @@ impl Rule for NoCaseDeclarations {
                 match stmt {
                     Statement::FunctionDeclaration(d) => {
                         let start = d.span.start;
-                        let end = start + 8;
-                        let span = Span::new(start, end);
+                        // `function`, `function*`, `async function` or `async function*`
+                        let span = d.id.as_ref().map_or(Span::new(start, start + 8), |id| {
+                            let keywords = ctx.source_range(Span::new(start, id.span.start));
+                            #[expect(clippy::cast_possible_truncation)]
+                            Span::sized(start, keywords.trim_end().len() as u32)
+                        });
                         report_with_add_braces_suggestion(ctx, span, switch_case);
                     }
                     Statement::ClassDeclaration(d) => {
@@ -161,6 +165,9 @@ fn test() {
         ("switch (a) { default: class C {} break; }"),
         ("switch (a) { default: using x = {}; break; }"),
         ("switch (a) { default: await using x = {}; break; }"),
+        ("switch (a) { case 1: async function f() {} break; }"),
+        ("switch (a) { case 1: function* f() {} break; }"),
+        ("switch (a) { case 1: async function * f() {} break; }"),
     ];
 
     let fix = vec![
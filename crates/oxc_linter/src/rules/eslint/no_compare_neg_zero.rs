@@ -127,7 +127,7 @@ fn is_neg_zero(expr: &Expression) -> bool {
     if unary.operator != UnaryOperator::UnaryNegation {
         return false;
     }
-    match &unary.argument {
+    match unary.argument.get_inner_expression() {
         Expression::NumericLiteral(number) => number.value == 0.0,
         Expression::BigIntLiteral(bigint) => bigint.is_zero(),
         _ => false,
@@ -166,6 +166,9 @@ fn test() {
         ("x !== 0", None),
         ("0 !== x", None),
         ("Object.is(x, -0)", None),
+        ("x === 0.0", None),
+        ("x === -0.1", None),
+        ("x === -x", None),
     ];
 
     let fail = vec![
@@ -181,6 +184,11 @@ fn test() {
         ("-0 < x", None),
         ("x <= -0", None),
         ("-0 <= x", None),
+        ("x !== -0", None),
+        ("x != -0", None),
+        ("x === -0.0", None),
+        ("-0.0 < x", None),
+        ("x === -(0)", None),
         // BigInt Literal
         ("-0n <= x", None),
     ];
@@ -199,6 +207,10 @@ fn test() {
         ("x <= -0", "x <= 0", None),
         ("-0 <= x", "0 <= x", None),
         ("-0n <= x", "0n <= x", None),
+        ("x !== -0", "x !== 0", None),
+        ("x === -0.0", "Object.is(x, -0)", None),
+        ("-0.0 < x", "0.0 < x", None),
+        ("x < -(0)", "x < (0)", None),
         ("x > (-0)", "x > (0)", None),
         ("(-0) > x", "(0) > x", None),
         ("x <= (-0n)", "x <= (0n)", None),
@@ -45,7 +45,7 @@ declare_oxc_lint!(
     /// ```
     NoConstructorReturn,
     eslint,
-    pedantic,
+    correctness,
     version = "0.4.3",
     short_description = "Disallow returning a value from a constructor.",
 );
@@ -106,11 +106,21 @@ fn test() {
         "class C { constructor() { this.fn = () => { return true } } }",
         "class C { constructor() { return } }",
         "class C { constructor() { { return } } }",
+        "class C { constructor() { const o = { m() { return 1 } } } }",
+        "class C { constructor() { const o = { get v() { return 1 } } } }",
+        "class C { constructor() { this.fn = () => 1 } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
+        "class C { static constructor() { return 1 } }",
+        "const o = { constructor() { return 1 } }",
     ];
 
     let fail = vec![
         "class C { constructor() { return '' } }",
         "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { return this } }",
+        "const C = class { constructor() { return {} } }",
+        "class C { constructor() { try { return 1 } finally {} } }",
+        "class C { constructor() { class D { constructor() { return 1 } } } }",
     ];
 
     Tester::new(NoConstructorReturn::NAME, NoConstructorReturn::PLUGIN, pass, fail)
@@ -140,12 +140,15 @@ impl<'a> Visit<'a> for ControlCharacterFinder<'a> {
         // Control characters are in the range 0x00 to 0x1F
         if ch.value <= 0x1F {
             let text: &str = ch.span.source_text(self.source_text);
+            // Unescaped control character in source text
+            let is_raw_control_char = text.chars().next().is_some_and(|c| c as u32 == ch.value);
             let is_code_point_match = text
                 .trim_start_matches('\\')
                 .chars()
                 .nth(0)
                 .is_some_and(|c| c.to_digit(16) == Some(ch.value));
-            if is_code_point_match
+            if is_raw_control_char
+                || is_code_point_match
                 || text.starts_with("\\x")
                 || text.starts_with("\\\\x")
                 || text.starts_with("\\u")
@@ -320,6 +323,10 @@ mod tests {
             "new RegExp(('\\x1f'))",
             "new RegExp((('\\x1f')))",
             "new RegExp('\\x1f' as string)",
+            // raw control characters
+            "var regex = /\u{1f}/",
+            "var regex = /a\u{1}b/",
+            "var regex = new RegExp('\u{1f}')",
         ];
 
         // test cases taken from eslint. See:
@@ -1,6 +1,7 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use oxc_syntax::class::{ElementId, ElementKind};
 use rustc_hash::FxHashMap;
 
 use crate::{context::LintContext, rule::Rule};
@@ -21,6 +22,14 @@ fn no_dupe_class_members_diagnostic(
 #[derive(Debug, Default, Clone)]
 pub struct NoDupeClassMembers;
 
+/// Latest declarations of a class member name, grouped by kind.
+#[derive(Default)]
+struct DefinedKinds {
+    getter: Option<ElementId>,
+    setter: Option<ElementId>,
+    other: Option<ElementId>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -64,23 +73,27 @@ declare_oxc_lint!(
 impl Rule for NoDupeClassMembers {
     fn run_once(&self, ctx: &LintContext) {
         ctx.classes().iter_enumerated().for_each(|(class_id, _)| {
-            let mut defined_elements = FxHashMap::default();
+            let mut defined_elements: FxHashMap<_, DefinedKinds> = FxHashMap::default();
             let elements = &ctx.classes().elements[class_id];
             for (element_id, element) in elements.iter_enumerated() {
-                if let Some(prev_element_id) = defined_elements.insert(&element.name, element_id) {
-                    let prev_element = &elements[prev_element_id];
-                    if element.r#static == prev_element.r#static
-                        && element.is_private == prev_element.is_private
-                        && (!(element.kind.is_setter_or_getter()
-                            && prev_element.kind.is_setter_or_getter())
-                            || element.kind == prev_element.kind)
-                    {
-                        ctx.diagnostic(no_dupe_class_members_diagnostic(
-                            &element.name,
-                            prev_element.span,
-                            element.span,
-                        ));
-                    }
+                let defined = defined_elements
+                    .entry((&element.name, element.r#static, element.is_private))
+                    .or_default();
+                // A getter and a setter for the same key don't conflict with each other,
+                // but anything else conflicts with every previous declaration.
+                let prev_element_id = if element.kind.contains(ElementKind::Getter) {
+                    defined.getter.replace(element_id).max(defined.other)
+                } else if element.kind.contains(ElementKind::Setter) {
+                    defined.setter.replace(element_id).max(defined.other)
+                } else {
+                    defined.other.replace(element_id).max(defined.getter).max(defined.setter)
+                };
+                if let Some(prev_element_id) = prev_element_id {
+                    ctx.diagnostic(no_dupe_class_members_diagnostic(
+                        &element.name,
+                        elements[prev_element_id].span,
+                        element.span,
+                    ));
                 }
             }
         });
@@ -137,6 +150,8 @@ fn test() {
         "class A { *foo() {} *bar() {} *baz() {} }",
         "class A { get foo() {} get bar() {} get baz() {} }",
         "class Foo { foo(a: string): string; foo(a: number): number; foo(a: any): any {} }",
+        "class A { get foo() {} static foo() {} set foo(value) {} }",
+        "class A { #foo() {} foo() {} static #bar() {} bar() {} }",
     ];
 
     let fail = vec![
@@ -179,6 +194,11 @@ fn test() {
         "class A { set foo(value) {}  foo() {}}",
         "class A { foo;  foo = 42;}",
         "class A { foo;  foo() {}}",
+        "class A { get foo() {} set foo(value) {} get foo() {} }",
+        "class A { get foo() {} set foo(value) {} foo() {} }",
+        "class A { static foo() {} foo() {} static foo() {} }",
+        "class A { foo; #foo; foo; }",
+        "class A { #foo() {} get #foo() {} }",
     ];
 
     Tester::new(NoDupeClassMembers::NAME, NoDupeClassMembers::PLUGIN, pass, fail)
@@ -319,6 +319,9 @@ fn test() {
         "if (a) {} else if (a && a) {}",
         "if (a && a) {} else if (a && a) {}",
         "if (a && a) {} else if (a) {}",
+        "if (a\n||\tb) {}\nelse if (  a  ) {}",
+        "if (foo.bar(1,2)) {} else if (foo . bar ( 1 , 2 )) {}",
+        "if (a && (b ||\n c)) {} else if (\n  (c || b) && a\n) {}",
     ];
 
     Tester::new(NoDupeElseIf::NAME, NoDupeElseIf::PLUGIN, pass, fail).test_and_snapshot();
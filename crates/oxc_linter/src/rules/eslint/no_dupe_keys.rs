@@ -88,11 +88,8 @@ impl Rule for NoDupeKeys {
             if is_proto_setter_property(prop, &name) {
                 continue;
             }
-            if let Some((prev_kind, prev_span)) = map.insert(name, (prop.kind, prop.key.span()))
-                && (prev_kind == PropertyKind::Init
-                    || prop.kind == PropertyKind::Init
-                    || prev_kind == prop.kind)
-            {
+            let seen: &mut SeenKinds = map.entry(name).or_default();
+            if let Some(prev_span) = seen.insert(prop.kind, prop.key.span()) {
                 let name = prop_key_name(&prop.key, ctx);
                 ctx.diagnostic(no_dupe_keys_diagnostic(prev_span, prop.key.span(), name));
             }
@@ -100,6 +97,28 @@ impl Rule for NoDupeKeys {
     }
 }
 
+/// Spans of the last property of each kind seen for a key.
+#[derive(Default)]
+struct SeenKinds {
+    init: Option<Span>,
+    get: Option<Span>,
+    set: Option<Span>,
+}
+
+impl SeenKinds {
+    /// Record a property of `kind`, and return the span of the most recent earlier property
+    /// which it duplicates. A getter and a setter don't duplicate each other.
+    fn insert(&mut self, kind: PropertyKind, span: Span) -> Option<Span> {
+        let (conflicts, slot) = match kind {
+            PropertyKind::Init => ([self.init, self.get, self.set], &mut self.init),
+            PropertyKind::Get => ([self.init, self.get, None], &mut self.get),
+            PropertyKind::Set => ([self.init, self.set, None], &mut self.set),
+        };
+        *slot = Some(span);
+        conflicts.into_iter().flatten().max_by_key(|span| span.start)
+    }
+}
+
 fn is_proto_setter_property(prop: &ObjectProperty<'_>, name: &str) -> bool {
     name == "__proto__"
         && prop.kind == PropertyKind::Init
@@ -151,6 +170,8 @@ fn test() {
         "var x = { get __proto__() {}, __proto__: null };", // { "ecmaVersion": 6 },
         "var x = { __proto__: null, set __proto__(value) {} };", // { "ecmaVersion": 6 },
         "var x = { set __proto__(value) {}, __proto__: null };", // { "ecmaVersion": 6 }
+        "var x = { get ['a']() {}, set a(value) {} };",
+        "var x = { set 'a'(value) {}, get [`a`]() {} };",
     ];
 
     let fail = vec![
@@ -179,6 +200,11 @@ fn test() {
         "var x = { ['__proto__']: null, get __proto__() {} };", // { "ecmaVersion": 6 },
         "var x = { ['__proto__']: null, set __proto__(value) {} };", // { "ecmaVersion": 6 },
         "var x = { __proto__: null, a: 5, a: 6 };", // { "ecmaVersion": 6 }
+        "var x = { get a() {}, get a() {} };",
+        "var x = { get a() {}, set a(value) {}, get a() {} };",
+        "var x = { set a(value) {}, get a() {}, set ['a'](value) {} };",
+        "var x = { ['a']: 1, a: 2 };",
+        r#"var x = { ["a"]: 1, get a() {} };"#,
     ];
 
     Tester::new(NoDupeKeys::NAME, NoDupeKeys::PLUGIN, pass, fail).test_and_snapshot();
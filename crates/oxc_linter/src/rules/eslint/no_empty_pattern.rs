@@ -128,7 +128,7 @@ impl NoEmptyPattern {
             return parameter
                 .initializer
                 .as_ref()
-                .is_none_or(|expr| matches!(&**expr, Expression::ObjectExpression(expr) if expr.properties.is_empty()));
+                .is_none_or(|expr| matches!(expr.without_parentheses(), Expression::ObjectExpression(expr) if expr.properties.is_empty()));
         }
         false
     }
@@ -169,6 +169,14 @@ fn test() {
             "var foo = ({} = {}) => {}",
             Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
         ), // { "ecmaVersion": 6 }
+        (
+            "var foo = ({} = ({})) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({}: Props = {}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -50,8 +50,8 @@ declare_oxc_lint!(
     /// ```
     NoEmptyStaticBlock,
     eslint,
-    correctness,
-    suggestion,
+    suspicious,
+    fix,
     version = "0.0.19",
     short_description = "Disallow empty static blocks.",
 );
@@ -64,10 +64,9 @@ impl Rule for NoEmptyStaticBlock {
             if ctx.has_comments_between(static_block.span) {
                 return;
             }
-            ctx.diagnostic_with_suggestion(
-                no_empty_static_block_diagnostic(static_block.span),
-                |fixer| fixer.delete(&static_block.span),
-            );
+            ctx.diagnostic_with_fix(no_empty_static_block_diagnostic(static_block.span), |fixer| {
+                fixer.delete(&static_block.span)
+            });
         }
     }
 }
@@ -83,6 +82,8 @@ fn test() {
 			// comment
 			} }",
         "class Foo { static { bar(); } static { bar(); } }",
+        "class Foo { static { ; } }",
+        "class Foo { static() {} }",
     ];
 
     let fail = vec![
@@ -92,6 +93,8 @@ fn test() {
 
 			 } }",
         "class Foo { static { bar(); } static {} }",
+        "class Foo { static {} static { bar(); } }",
+        "const Foo = class { static {} }",
     ];
 
     let fix = vec![
@@ -104,6 +107,8 @@ fn test() {
             "class Foo {  }",
         ),
         ("class Foo { static { bar(); } static {} }", "class Foo { static { bar(); }  }"),
+        ("class Foo { static {} static { bar(); } }", "class Foo {  static { bar(); } }"),
+        ("const Foo = class { static {} }", "const Foo = class {  }"),
     ];
 
     Tester::new(NoEmptyStaticBlock::NAME, NoEmptyStaticBlock::PLUGIN, pass, fail)
@@ -137,8 +137,12 @@ impl Rule for NoIrregularWhitespace {
         }
 
         // Report irregular whitespace inside comments when not skipping them.
+        // Hashbang is a comment in ESLint, but isn't included in `comments()`.
         if !self.skip_comments {
             let source_text = ctx.semantic().source_text();
+            if let Some(hashbang) = &ctx.nodes().program().hashbang {
+                report_irregular_whitespace_in_span(ctx, source_text, hashbang.span);
+            }
             for comment in ctx.semantic().comments() {
                 report_irregular_whitespace_in_span(ctx, source_text, comment.span);
             }
@@ -175,6 +179,7 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("#!/usr/bin/env　node\nfoo();", None),
         (r"'\u000B';", None),
         (r"'\u000C';", None),
         (r"'\u0085';", None),
@@ -467,6 +472,7 @@ fn test() {
         ("var any = /　/, other = /​/;", Some(serde_json::json!([{ "skipRegExps": false }]))),
         ("var any = `　`, other = `​`;", Some(serde_json::json!([{ "skipTemplates": false }]))),
         ("<div>　</div>;", Some(serde_json::json!([{ "skipJSXText": false }]))),
+        ("#!/usr/bin/env　node\nfoo();", Some(serde_json::json!([{ "skipComments": false }]))),
     ];
 
     Tester::new(NoIrregularWhitespace::NAME, NoIrregularWhitespace::PLUGIN, pass, fail)
@@ -1,9 +1,9 @@
 use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, ast_util::could_be_asi_hazard, context::LintContext, rule::Rule};
 
 fn no_object_constructor_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow calls to the `Object` constructor without an argument")
@@ -11,17 +11,28 @@ fn no_object_constructor_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+fn no_object_constructor_with_argument_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected call to the `Object` constructor")
+        .with_help(
+            "The global `Object` may be redefined; avoid relying on it to convert values to objects",
+        )
+        .with_label(span)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct NoObjectConstructor;
 
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Disallow calls to the Object constructor without an argument.
+    /// Disallow calls to the Object constructor.
     ///
     /// ### Why is this bad?
     ///
-    /// Use of the Object constructor to construct a new empty object is generally discouraged in favor of object literal notation because of conciseness and because the Object global may be redefined. The exception is when the Object constructor is used to intentionally wrap a specified value which is passed as an argument.
+    /// Use of the Object constructor to construct a new empty object is generally discouraged in favor of object literal notation because of conciseness and because the Object global may be redefined.
+    ///
+    /// Calls with an argument, which wrap the argument in an object, are reported too,
+    /// but are not fixed, as they can't be replaced with an object literal.
     ///
     /// ### Examples
     ///
@@ -29,21 +40,21 @@ declare_oxc_lint!(
     /// ```js
     /// Object();
     /// new Object();
+    /// Object("foo");
+    /// const isObject = value => value === Object(value);
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```js
-    /// Object("foo");
     /// const obj = { a: 1, b: 2 };
-    /// const isObject = value => value === Object(value);
     /// const createObject = Object => new Object();
     /// ```
     NoObjectConstructor,
     eslint,
-    pedantic,
-    pending,
+    style,
+    conditional_fix,
     version = "0.13.2",
-    short_description = "Disallow calls to the Object constructor without an argument.",
+    short_description = "Disallow calls to the Object constructor.",
 );
 
 impl Rule for NoObjectConstructor {
@@ -62,14 +73,61 @@ impl Rule for NoObjectConstructor {
             return;
         };
 
-        if ident.name == "Object"
-            && ctx.is_reference_to_global_variable(ident)
-            && arguments.is_empty()
-            && type_parameters.is_none()
-        {
-            ctx.diagnostic(no_object_constructor_diagnostic(span));
+        if ident.name != "Object" || !ctx.is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        if !arguments.is_empty() {
+            ctx.diagnostic(no_object_constructor_with_argument_diagnostic(span));
+            return;
+        }
+
+        if type_parameters.is_none() {
+            let diagnostic = no_object_constructor_diagnostic(span);
+            if ctx.has_comments_between(span) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                let replacement = match statement_parent(node, ctx) {
+                    None => "{}",
+                    // A leading `;` would become the body of e.g. `if (a) Object();`.
+                    Some(parent)
+                        if matches!(
+                            parent.kind(),
+                            AstKind::Program(_)
+                                | AstKind::FunctionBody(_)
+                                | AstKind::BlockStatement(_)
+                                | AstKind::StaticBlock(_)
+                                | AstKind::SwitchCase(_)
+                        ) && could_be_asi_hazard(node, ctx) =>
+                    {
+                        ";({})"
+                    }
+                    Some(_) => "({})",
+                };
+                fixer.replace(span, replacement)
+            });
+        }
+    }
+}
+
+/// If `node` begins an expression statement (including the expression body of an arrow
+/// function), where a bare `{}` would be parsed as a block, returns the statement's parent.
+fn statement_parent<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let start = node.span().start;
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        if ancestor.span().start != start {
+            return None;
+        }
+        if matches!(ancestor.kind(), AstKind::ExpressionStatement(_)) {
+            return Some(ctx.nodes().parent_node(ancestor.id()));
         }
     }
+    None
 }
 
 #[test]
@@ -77,8 +135,6 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("new Object(x)", None, None),
-        ("Object(x)", None, None),
         ("new globalThis.Object", None, None),
         ("const createObject = Object => new Object()", None, None),
         ("var Object; new Object;", None, None),
@@ -88,6 +144,10 @@ fn test() {
     let fail = vec![
         ("new Object", None, None),
         ("Object()", None, None),
+        // Calls with an argument are reported, but not fixed
+        ("new Object(x)", None, None),
+        ("Object(x)", None, None),
+        ("const isObject = value => value === Object(value);", None, None),
         ("const fn = () => Object();", None, None),
         ("Object() instanceof Object;", None, None),
         ("const obj = Object?.();", None, None),
@@ -260,8 +320,30 @@ fn test() {
             None,
             None,
         ),
+        ("const obj = Object(/* empty */);", None, None),
+        ("const obj = new Object;", None, None),
+    ];
+
+    let fix = vec![
+        ("new Object", "({})", None),
+        ("Object()", "({})", None),
+        ("const obj = new Object;", "const obj = {};", None),
+        ("const obj = Object?.();", "const obj = {};", None),
+        ("const fn = () => Object();", "const fn = () => ({});", None),
+        ("Object() instanceof Object;", "({}) instanceof Object;", None),
+        ("(new Object() instanceof Object);", "({} instanceof Object);", None),
+        ("foo: Object();", "foo: ({});", None),
+        ("foo();Object();", "foo();({});", None),
+        ("if (a) Object();", "if (a) ({});", None),
+        ("do Object(); while (a);", "do ({}); while (a);", None),
+        ("foo()\nObject()", "foo()\n;({})", None),
+        ("var foo = { bar: baz }\nObject()", "var foo = { bar: baz }\n;({})", None),
+        ("const obj = Object(/* empty */);", "const obj = Object(/* empty */);", None),
+        ("Object(x)", "Object(x)", None),
+        ("new Object(x)", "new Object(x)", None),
     ];
 
     Tester::new(NoObjectConstructor::NAME, NoObjectConstructor::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
@@ -1,17 +1,18 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, Expression, FunctionBody},
+    ast::{Argument, Expression, FunctionBody, ReturnStatement},
 };
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::{
     AstNode,
     context::LintContext,
+    fixer::{RuleFix, RuleFixer},
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -114,8 +115,8 @@ declare_oxc_lint!(
     /// ```
     NoPromiseExecutorReturn,
     eslint,
-    pedantic,
-    pending,
+    correctness,
+    suggestion,
     config = NoPromiseExecutorReturnConfig,
     version = "1.33.0",
     short_description = "Disallow returning values from Promise executor functions.",
@@ -155,7 +156,33 @@ impl Rule for NoPromiseExecutorReturn {
                     if self.allow_void && expr.get_inner_expression().is_void() {
                         return;
                     }
-                    ctx.diagnostic(no_promise_executor_return_diagnostic(arrow.body.span));
+                    let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+                    let mut suggestions = Vec::with_capacity(2);
+                    if self.allow_void {
+                        suggestions.push(prepend_void(
+                            fixer,
+                            expr.span(),
+                            needs_parens_for_void(expr),
+                            false,
+                        ));
+                    }
+                    // `{function () {}}` and `{class {}}` would be invalid declarations
+                    if !matches!(expr, Expression::FunctionExpression(func) if func.id.is_none())
+                        && !matches!(expr, Expression::ClassExpression(class) if class.id.is_none())
+                    {
+                        suggestions.push(
+                            fixer
+                                .replace(
+                                    expr.span(),
+                                    format!("{{{}}}", fixer.source_range(expr.span())),
+                                )
+                                .with_message("Wrap the expression in `{}`"),
+                        );
+                    }
+                    ctx.diagnostic_with_suggestions(
+                        no_promise_executor_return_diagnostic(arrow.body.span),
+                        suggestions,
+                    );
                 } else {
                     // Arrow function with block body: check for return statements
                     self.check_function_body(&arrow.body, ctx);
@@ -176,25 +203,68 @@ impl NoPromiseExecutorReturn {
         let mut finder = ReturnStatementFinder::new(self.allow_void);
         finder.visit_function_body(body);
 
-        for span in finder.return_spans {
-            ctx.diagnostic(no_promise_executor_return_diagnostic(span));
+        for ret in finder.return_statements {
+            let diagnostic = no_promise_executor_return_diagnostic(ret.span);
+            if !self.allow_void {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+            // `return(1)` -> `return void (1)`
+            let leading_space = !ctx
+                .source_range(Span::new(ret.span.start, ret.argument_span.start))
+                .ends_with(char::is_whitespace);
+            let fixer = RuleFixer::new(FixKind::Suggestion, ctx);
+            ctx.diagnostic_with_suggestions(
+                diagnostic,
+                [prepend_void(fixer, ret.argument_span, ret.argument_needs_parens, leading_space)],
+            );
         }
     }
 }
 
+/// Suggest prepending `void` to the expression at `span`.
+fn prepend_void(fixer: RuleFixer, span: Span, needs_parens: bool, leading_space: bool) -> RuleFix {
+    let text = fixer.source_range(span);
+    let space = if leading_space { " " } else { "" };
+    let replacement =
+        if needs_parens { format!("{space}void ({text})") } else { format!("{space}void {text}") };
+    fixer.replace(span, replacement).with_message("Prepend `void` to the expression")
+}
+
+fn needs_parens_for_void(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::SequenceExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ConditionalExpression(_)
+            | Expression::LogicalExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::TSAsExpression(_)
+            | Expression::TSSatisfiesExpression(_)
+    )
+}
+
+struct ValueReturn {
+    span: Span,
+    argument_span: Span,
+    argument_needs_parens: bool,
+}
+
 struct ReturnStatementFinder {
-    return_spans: Vec<Span>,
+    return_statements: Vec<ValueReturn>,
     allow_void: bool,
 }
 
 impl ReturnStatementFinder {
     fn new(allow_void: bool) -> Self {
-        Self { return_spans: Vec::new(), allow_void }
+        Self { return_statements: Vec::new(), allow_void }
     }
 }
 
 impl Visit<'_> for ReturnStatementFinder {
-    fn visit_return_statement(&mut self, it: &oxc_ast::ast::ReturnStatement<'_>) {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'_>) {
         // Empty return is allowed
         let Some(argument) = &it.argument else {
             return;
@@ -205,7 +275,11 @@ impl Visit<'_> for ReturnStatementFinder {
             return;
         }
 
-        self.return_spans.push(it.span);
+        self.return_statements.push(ValueReturn {
+            span: it.span,
+            argument_span: argument.span(),
+            argument_needs_parens: needs_parens_for_void(argument),
+        });
     }
 
     fn visit_function(
@@ -223,7 +297,7 @@ impl Visit<'_> for ReturnStatementFinder {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{ExpectFixTestCase, Tester};
 
     let pass = vec![
         ("function foo(resolve, reject) { return 1; }", None),
@@ -399,11 +473,90 @@ fn test() {
         ("() => new Promise(() => async () => 1);", None), // { "ecmaVersion": 2017 }
         ("() => new Promise(() => function () {});", None),
         ("() => new Promise(() => function foo() {});", None),
+        ("() => new Promise(() => class {});", None),
         ("() => new Promise(() => []);", None),
         ("new Promise((Promise) => { return 1; })", None),
         ("new Promise(function Promise(resolve, reject) { return 1; })", None),
     ];
 
+    let allow_void = || Some(serde_json::json!([{ "allowVoid": true }]));
+    let fix: Vec<ExpectFixTestCase> = vec![
+        ("new Promise(r => null)", "new Promise(r => {null})", None).into(),
+        ("() => new Promise(() => ({}));", "() => new Promise(() => {({})});", None).into(),
+        (
+            "() => new Promise(() => function () {});",
+            "() => new Promise(() => function () {});",
+            None,
+        )
+            .into(),
+        ("() => new Promise(() => class {});", "() => new Promise(() => class {});", None).into(),
+        ("new Promise(() => { return 1; })", "new Promise(() => { return 1; })", None).into(),
+        (
+            "new Promise((resolve, reject) => resolve(1))",
+            (
+                "new Promise((resolve, reject) => void resolve(1))",
+                "new Promise((resolve, reject) => {resolve(1)})",
+            ),
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(r => 1 ? 2 : 3)",
+            ("new Promise(r => void (1 ? 2 : 3))", "new Promise(r => {1 ? 2 : 3})"),
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(r => (1 ? 2 : 3))",
+            ("new Promise(r => void (1 ? 2 : 3))", "new Promise(r => {(1 ? 2 : 3)})"),
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(r => () => {})",
+            ("new Promise(r => void (() => {}))", "new Promise(r => {() => {}})"),
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(r => /*hi*/ ~0)",
+            ("new Promise(r => /*hi*/ void ~0)", "new Promise(r => /*hi*/ {~0})"),
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(resolve => r = resolve)",
+            ("new Promise(resolve => void (r = resolve))", "new Promise(resolve => {r = resolve})"),
+            allow_void(),
+        )
+            .into(),
+        ("new Promise(r =>1)", ("new Promise(r =>void 1)", "new Promise(r =>{1})"), allow_void())
+            .into(),
+        (
+            "() => new Promise(() => function () {});",
+            "() => new Promise(() => void function () {});",
+            allow_void(),
+        )
+            .into(),
+        ("new Promise(r => { return 0 })", "new Promise(r => { return void 0 })", allow_void())
+            .into(),
+        ("new Promise(r => { return(1) })", "new Promise(r => { return void (1) })", allow_void())
+            .into(),
+        (
+            "new Promise(resolve => { return (foo = resolve(1)); })",
+            "new Promise(resolve => { return void (foo = resolve(1)); })",
+            allow_void(),
+        )
+            .into(),
+        (
+            "new Promise(r => { return a || b })",
+            "new Promise(r => { return void (a || b) })",
+            allow_void(),
+        )
+            .into(),
+    ];
+
     Tester::new(NoPromiseExecutorReturn::NAME, NoPromiseExecutorReturn::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .test_and_snapshot();
 }
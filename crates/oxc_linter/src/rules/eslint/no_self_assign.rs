@@ -183,6 +183,18 @@ impl NoSelfAssign {
 
                     i += 1;
                 }
+
+                // `[a, ...b] = [a, ...b]`
+                // Rest element only matches a spread element which is the last element,
+                // to avoid cases such as `[...a] = [...a, 1]`.
+                if i == array_pattern.elements.len()
+                    && let Some(rest) = &array_pattern.rest
+                    && array_expr.elements.len() == i + 1
+                    && let Some(ArrayExpressionElement::SpreadElement(spread)) =
+                        array_expr.elements.last()
+                {
+                    self.each_self_assignment(&rest.target, &spread.argument, ctx);
+                }
             }
 
             AssignmentTarget::ObjectAssignmentTarget(object_pattern) => {
@@ -393,6 +405,8 @@ fn test() {
         ("[a, b] = [a, c]", None),
         ("[a, b] = [, b]", None),
         ("[a, ...b] = [a, ...b]", None),
+        ("[...a] = [...a]", None),
+        ("[a, ...[b]] = [a, ...[b]]", None),
         ("[[a], {b}] = [[a], {b}]", None),
         ("({a} = {a})", None),
         ("({a: b} = {a: b})", None),
@@ -1,7 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, MethodDefinitionKind, PropertyKind},
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
@@ -37,6 +40,24 @@ declare_oxc_lint!(
     ///     return true;
     ///   }
     /// }
+    ///
+    /// Object.defineProperty(foo, "bar", {
+    ///   set(value) {
+    ///     return value;
+    ///   }
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// class URL {
+    ///   set origin(value) {
+    ///     if (!value) {
+    ///       return;
+    ///     }
+    ///     this._origin = value;
+    ///   }
+    /// }
     /// ```
     NoSetterReturn,
     eslint,
@@ -47,26 +68,109 @@ declare_oxc_lint!(
 
 impl Rule for NoSetterReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(stmt) = node.kind() else {
-            return;
-        };
-        if stmt.argument.is_none() {
-            return;
+        match node.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                if stmt.argument.is_none() {
+                    return;
+                }
+                let Some(function) = ctx.nodes().ancestors(node.id()).find(|node| {
+                    matches!(
+                        node.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                }) else {
+                    return;
+                };
+                if is_setter(function, ctx) {
+                    ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                if let Some(expr) = arrow.get_expression()
+                    && is_descriptor_setter(node, ctx)
+                {
+                    ctx.diagnostic(no_setter_return_diagnostic(expr.span()));
+                }
+            }
+            _ => {}
         }
+    }
+}
 
-        for scope_id in ctx.scoping().scope_ancestors(node.scope_id()) {
-            let flags = ctx.scoping().scope_flags(scope_id);
-            if flags.is_set_accessor() {
-                ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
-            } else if flags.is_function() {
-                break;
-            }
+/// Methods which take a property descriptor as their third argument.
+const DESCRIPTOR_METHODS: [(&str, &str); 2] =
+    [("Object", "defineProperty"), ("Reflect", "defineProperty")];
+
+/// Methods which take an object of property descriptors as their second argument.
+const DESCRIPTORS_METHODS: [(&str, &str); 2] =
+    [("Object", "defineProperties"), ("Object", "create")];
+
+fn is_setter(function: &AstNode, ctx: &LintContext) -> bool {
+    match ctx.nodes().parent_kind(function.id()) {
+        // Ignore functions in computed keys, e.g. `({ set [function() {}](val) {} })`
+        AstKind::MethodDefinition(method) if method.kind == MethodDefinitionKind::Set => {
+            method.value.span == function.span()
         }
+        AstKind::ObjectProperty(property) if property.kind == PropertyKind::Set => {
+            property.value.span() == function.span()
+        }
+        _ => is_descriptor_setter(function, ctx),
+    }
+}
+
+/// Check if `function` is the `set` function of a property descriptor, e.g.
+/// `Object.defineProperty(foo, 'bar', { set(val) {} })`.
+fn is_descriptor_setter(function: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let property = nodes.parent_node(function.id());
+    let AstKind::ObjectProperty(prop) = property.kind() else {
+        return false;
+    };
+    if prop.kind != PropertyKind::Init || !prop.key.is_specific_static_name("set") {
+        return false;
     }
+
+    let descriptor = nodes.parent_node(property.id());
+    let descriptor_parent = nodes.parent_node(descriptor.id());
+    let (argument, call, methods, index) = match descriptor_parent.kind() {
+        // `Object.defineProperty(foo, 'bar', { set(val) {} })`
+        AstKind::CallExpression(call) => (descriptor, call, &DESCRIPTOR_METHODS, 2),
+        // `Object.defineProperties(foo, { bar: { set(val) {} } })`
+        AstKind::ObjectProperty(prop) if prop.kind == PropertyKind::Init => {
+            let descriptors = nodes.parent_node(descriptor_parent.id());
+            let AstKind::CallExpression(call) = nodes.parent_kind(descriptors.id()) else {
+                return false;
+            };
+            (descriptors, call, &DESCRIPTORS_METHODS, 1)
+        }
+        _ => return false,
+    };
+
+    let member_expr = match call.callee.without_parentheses() {
+        Expression::ChainExpression(chain) => chain.expression.member_expression(),
+        callee => callee.as_member_expression(),
+    };
+    let Some(member_expr) = member_expr else {
+        return false;
+    };
+    let Expression::Identifier(object) = member_expr.object().without_parentheses() else {
+        return false;
+    };
+    let Some(method) = member_expr.static_property_name() else {
+        return false;
+    };
+
+    methods
+        .iter()
+        .any(|&(object_name, method_name)| object.name == object_name && method == method_name)
+        && call.arguments.get(index).is_some_and(|arg| arg.span() == argument.span())
+        && ctx.is_reference_to_global_variable(object)
 }
 
 #[test]
 fn test() {
+    use serde_json::json;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -170,9 +274,8 @@ fn test() {
         "reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })",
         "Reflect.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
         "object.create(foo, { bar: { set: function(val) { return 1; } } })",
-        "Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })",
-        "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-        "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
+        // We do not support configuring globals with comments:
+        // "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
         "let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
         "function f() { Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } }); var Reflect;}",
         "function f(Object) { Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } }) }",
@@ -215,31 +318,49 @@ fn test() {
         "x = function f(){}; class A { set a(val) { return 1; } };",
         "x = () => {}; A = class { set a(val) { return 1; } };",
         "return; ({ set a(val) { return 1; } }); return 2;",
-        // "Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } } })",
-        // "Object.create(null, { baz: { set(val) { return 1; } } })",
-        // "Object.defineProperty(foo, 'bar', { set: val => val })",
-        // "Reflect.defineProperty(foo, 'bar', { set: val => f(val) })",
-        // "Object.defineProperties(foo, { baz: { set: val => a + b } })",
-        // "Object.create({}, { baz: { set: val => this._val } })",
-        // "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
-        // "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
-        // "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
-        // "Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })",
-        // "Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })",
-        // "Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })",
-        // "Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })",
-        // "Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })",
-        // "Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })",
-        // "Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })",
-        // "Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-        // "(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })",
+        "Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+        "Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+        "Object.defineProperties(foo, { baz: { set(val) { return 1; } } })",
+        "Object.create(null, { baz: { set(val) { return 1; } } })",
+        "Object.defineProperty(foo, 'bar', { set: val => val })",
+        "Reflect.defineProperty(foo, 'bar', { set: val => f(val) })",
+        "Object.defineProperties(foo, { baz: { set: val => a + b } })",
+        "Object.create({}, { baz: { set: val => this._val } })",
+        "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
+        "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
+        "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
+        "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
+        "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
+        "Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })",
+        "Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })",
+        "Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })",
+        "Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })",
+        "Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })",
+        "Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })",
+        "Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })",
+        "Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+        "(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })",
+        "({ set a(val) { ({ set b(val) { return 1; } }); } })",
     ];
 
     Tester::new(NoSetterReturn::NAME, NoSetterReturn::PLUGIN, pass, fail)
         .change_rule_path_extension("js")
         .test_and_snapshot();
+
+    let pass = vec![
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })",
+            None,
+            Some(json!({ "globals": { "Reflect": "off" } })),
+        ),
+        (
+            "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
+            None,
+            Some(json!({ "globals": { "Object": "off" } })),
+        ),
+    ];
+    let fail = vec![];
+    Tester::new(NoSetterReturn::NAME, NoSetterReturn::PLUGIN, pass, fail)
+        .change_rule_path_extension("js")
+        .test();
 }
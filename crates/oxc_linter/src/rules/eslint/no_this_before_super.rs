@@ -1,14 +1,18 @@
 use oxc_ast::{
     AstKind, AstType,
-    ast::{Argument, Expression, MethodDefinitionKind},
+    ast::{
+        Argument, ArrowFunctionExpression, ClassBody, Expression, Function, MethodDefinitionKind,
+        Super, ThisExpression,
+    },
 };
+use oxc_ast_visit::Visit;
 use oxc_cfg::{
     BlockNodeId, ControlFlowGraph, EdgeType, ErrorEdgeKind,
     graph::visit::{EdgeRef, neighbors_filtered_by_edge_weight},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::NodeId;
+use oxc_semantic::{NodeId, ScopeFlags};
 use oxc_span::{GetSpan, Span};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -259,26 +263,35 @@ impl NoThisBeforeSuper {
         })
     }
 
-    fn contains_this_or_super(arg: &Argument) -> bool {
-        match arg {
-            Argument::Super(_) | Argument::ThisExpression(_) => true,
-            Argument::CallExpression(call_expr) => {
-                matches!(&call_expr.callee, Expression::Super(_) | Expression::ThisExpression(_))
-                    || matches!(&call_expr.callee,
-                    Expression::StaticMemberExpression(static_member) if
-                    matches!(static_member.object, Expression::Super(_) | Expression::ThisExpression(_)))
-                    || Self::contains_this_or_super_in_args(&call_expr.arguments)
-            }
-            Argument::StaticMemberExpression(call_expr) => {
-                matches!(&call_expr.object, Expression::Super(_) | Expression::ThisExpression(_))
-            }
-            _ => false,
+    fn contains_this_or_super_in_args(args: &[Argument]) -> bool {
+        let mut finder = ThisOrSuperFinder { found: false };
+        for arg in args {
+            finder.visit_argument(arg);
         }
+        finder.found
     }
+}
 
-    fn contains_this_or_super_in_args(args: &[Argument]) -> bool {
-        args.iter().any(|arg| Self::contains_this_or_super(arg))
+/// Finds `this` or `super` which are evaluated immediately, i.e. not inside a nested function
+/// or class body.
+struct ThisOrSuperFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ThisOrSuperFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
     }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class_body(&mut self, _it: &ClassBody<'a>) {}
 }
 
 #[test]
@@ -373,6 +386,11 @@ fn test() {
         "class C extends B { field = this.foo(); }",
         "class C extends B { field = this.foo(); constructor() { super(); } }",
         "class C extends B { field = this.foo(); constructor() { } }", // < in this case, initializers are never evaluated.
+        "class A extends B { constructor() { super(() => this); } }",
+        "class A extends B { constructor() { super(function () { return this; }); } }",
+        "class A extends B { constructor() { a ? super() : super(); this.a(); } }",
+        "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } this.a(); } }",
+        "class A extends B { constructor() { do { super(); } while (foo); this.a(); } }",
     ];
 
     let fail = vec![
@@ -436,6 +454,13 @@ fn test() {
                 }
             }
         }",
+        "class A extends B { constructor() { super(this.a + 1); } }",
+        "class A extends B { constructor() { super([this]); } }",
+        "class A extends B { constructor() { super({ a: this }); } }",
+        "class A extends B { constructor() { super(...this.args); } }",
+        "class A extends B { constructor() { super(a ? this : b); } }",
+        "class A extends B { constructor() { switch (a) { case 0: super(); break; } this.a(); } }",
+        "class A extends B { constructor() { a ? super() : b; this.a(); } }",
         "class A extends B {
             constructor() {
                 while (foo) {
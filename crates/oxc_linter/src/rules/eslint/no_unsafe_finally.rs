@@ -8,12 +8,15 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, context::LintContext, rule::Rule};
 
-fn no_unsafe_finally_diagnostic(span: Span) -> OxcDiagnostic {
+fn no_unsafe_finally_diagnostic(span: Span, finally_span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unsafe `finally` block.")
         .with_help(
             "Control flow inside `try` or `catch` blocks will be overwritten by this statement.",
         )
-        .with_label(span)
+        .with_labels([
+            span.primary_label("This statement overrides the `try` and `catch` blocks"),
+            finally_span.label("Inside this `finally` block"),
+        ])
 }
 
 #[derive(Debug, Default, Clone)]
@@ -103,7 +106,18 @@ impl Rule for NoUnsafeFinally {
                 if label_name.is_some() && label_inside {
                     break;
                 }
-                ctx.diagnostic(no_unsafe_finally_diagnostic(node.kind().span()));
+                // Label the `finally` keyword rather than the whole block, so the label doesn't
+                // cover the reported statement when both are on the same line.
+                let keyword_search_start = try_stmt
+                    .handler
+                    .as_ref()
+                    .map_or(try_stmt.block.span.end, |handler| handler.span.end);
+                let finally_span = ctx
+                    .find_next_token_within(keyword_search_start, block_stmt.span.start, "finally")
+                    .map_or(Span::empty(block_stmt.span.start), |offset| {
+                        Span::sized(keyword_search_start + offset, 7)
+                    });
+                ctx.diagnostic(no_unsafe_finally_diagnostic(node.kind().span(), finally_span));
                 return;
             }
         }
@@ -166,6 +180,7 @@ fn test() {
         "var foo = function() { a: while (true) try {} finally { switch (true) { case true: break a; } } }",
         "var foo = function() { a: while (true) try {} finally { switch (true) { case true: continue; } } }",
         "var foo = function() { a: switch (true) { case true: try {} finally { switch (true) { case true: break a; } } } }",
+        "var foo = function() { try {} finally /* finally */ { return; } }",
     ];
 
     Tester::new(NoUnsafeFinally::NAME, NoUnsafeFinally::PLUGIN, pass, fail).test_and_snapshot();
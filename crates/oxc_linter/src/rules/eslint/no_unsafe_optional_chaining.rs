@@ -224,6 +224,15 @@ impl NoUnsafeOptionalChaining {
                 {
                     return Some(ErrorType::Usage);
                 }
+                AstKind::FormalParameter(param)
+                    if param.pattern.is_destructuring_pattern()
+                        && param
+                            .initializer
+                            .as_ref()
+                            .is_some_and(|expr| expr.node_id() == current_id) =>
+                {
+                    return Some(ErrorType::Usage);
+                }
                 AstKind::VariableDeclarator(decl)
                     if decl.id.is_destructuring_pattern()
                         && decl.init.as_ref().is_some_and(|expr| expr.node_id() == current_id) =>
@@ -335,6 +344,8 @@ fn test() {
         ("f?.<Q>();", None),
         ("a?.c?.b<c>", None),
         ("const baz = {...obj?.foo };", None),
+        ("function foo(a = obj?.b) {}", None),
+        ("const foo = ([a] = obj?.b ?? []) => a;", None),
     ];
 
     let fail = vec![
@@ -356,6 +367,46 @@ fn test() {
         ("const s = [], t = [...obj?.foo];", None),
         ("const c = () => ([...(obj?.foo)]);", None),
         ("bar + obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("(obj?.foo ?? bar?.baz).qux;", None),
+        ("(obj?.foo, bar?.baz).qux;", None),
+        ("(obj?.foo || bar?.baz).qux;", None),
+        ("(obj?.foo)!.bar;", None),
+        ("(obj?.foo as any).bar;", None),
+        ("(obj?.foo)[1];", None),
+        ("(obj?.#foo)();", None),
+        ("bar instanceof obj?.foo;", None),
+        ("const [foo] = obj?.bar;", None),
+        ("[foo] = obj?.bar;", None),
+        ("[{ foo } = obj?.bar] = [];", None),
+        ("({ a: { foo } = obj?.bar } = {});", None),
+        ("function foo({ a } = obj?.b) {}", None),
+        ("const foo = ([a] = obj?.b) => a;", None),
+        ("for (const [a] of obj?.b) {}", None),
+        ("async function foo() { (await obj?.foo)(); }", None),
+        ("async function foo() { (await obj?.foo).bar; }", None),
+        ("async function foo() { const { bar } = await obj?.foo; }", None),
+        ("obj?.foo + bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo - bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo * bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo / bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo % bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo ** bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("+obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("-obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar += obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar -= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar *= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar /= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar %= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar **= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        (
+            "(obj?.foo ?? bar?.baz) + 1;",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
+        (
+            "async function foo() { await obj?.foo + bar; }",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
     ];
 
     Tester::new(NoUnsafeOptionalChaining::NAME, NoUnsafeOptionalChaining::PLUGIN, pass, fail)
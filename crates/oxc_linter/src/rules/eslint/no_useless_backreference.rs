@@ -88,7 +88,7 @@ impl Rule for NoUselessBackreference {
 
             for bref in &collector.backrefs {
                 if let Some((problem, cap_group_span, bref_group_span)) =
-                    problems_for_backref(bref, &collector.nodes, &collector.groups)
+                    problem_for_backref(bref, &collector.nodes, &collector.groups)
                 {
                     ctx.diagnostic(no_useless_backreference_diagnostic(
                         bref.span,
@@ -194,6 +194,43 @@ impl<'ast> Visit<'ast> for RegexCollector<'ast> {
     }
 }
 
+/// Determines if a backreference is useless for every capturing group it may refer to.
+///
+/// A named backreference can resolve to several groups when the pattern uses duplicate
+/// named groups in different alternatives, e.g. `/(?<foo>a)|(?<foo>b)\k<foo>/`. In that
+/// case the backreference is only useless if it is useless for all of them, and the
+/// problem reported is the one found for the first group.
+fn problem_for_backref<'a>(
+    bref: &'a BackRefInfo<'a>,
+    nodes: &IndexVec<RegexNodeId, RegExpAstKind<'a>>,
+    groups: &'a [GroupInfo<'a>],
+) -> Option<(Problem, Span, Span)> {
+    match bref.target {
+        BackRefInfoTarget::Index(i) => {
+            let Some(cap_group) = groups.get(i as usize - 1) else {
+                debug_assert!(
+                    false,
+                    "a backreference must have a corresponding capture group, else it is an octal escape"
+                );
+                return None;
+            };
+            problems_for_backref(bref, nodes, cap_group)
+        }
+        BackRefInfoTarget::Name(cap_group_name) => {
+            let mut first_problem = None;
+            for cap_group in groups.iter().filter(|g| g.name.is_some_and(|n| n == cap_group_name)) {
+                let problem = problems_for_backref(bref, nodes, cap_group)?;
+                first_problem.get_or_insert(problem);
+            }
+            debug_assert!(
+                first_problem.is_some(),
+                "a named backreference must have a corresponding capture group"
+            );
+            first_problem
+        }
+    }
+}
+
 /// Determines if a backreference is useless — meaning it references a capturing
 /// group that hasn't (and cannot) participate at the time it's evaluated.
 ///
@@ -223,26 +260,12 @@ impl<'ast> Visit<'ast> for RegexCollector<'ast> {
 fn problems_for_backref<'a>(
     bref: &'a BackRefInfo<'a>,
     nodes: &IndexVec<RegexNodeId, RegExpAstKind<'a>>,
-    groups: &'a [GroupInfo<'a>],
+    cap_group: &GroupInfo<'a>,
 ) -> Option<(
     Problem,
     /* span of the backreference */ Span,
     /* span of the capture group */ Span,
 )> {
-    let cap_group = match bref.target {
-        BackRefInfoTarget::Index(i) => groups.get(i as usize - 1),
-        BackRefInfoTarget::Name(cap_group_name) => {
-            groups.iter().find(|g| g.name.is_some_and(|n| n == cap_group_name))
-        }
-    };
-    let Some(cap_group) = cap_group else {
-        debug_assert!(
-            false,
-            "a backreference must have a corresponding capture group, else it is an octal escape"
-        );
-        return None;
-    };
-
     // Problem::Nested
     // In this scenario, the backreference appears *inside* the group it refers to.
     // e.g. `/(a\1)/`
@@ -458,6 +481,8 @@ fn test() {
         r"new RegExp('([[A--B]])\\1', 'v')",
         r"new RegExp('[[]\\1](a)', 'v')",
         r"/((?<foo>bar)\k<foo>|(?<foo>baz))/",
+        r"/(?<foo>bar)|(?<foo>baz)\k<foo>/",
+        r"/(?:(?<foo>bar)|(?<foo>baz))\k<foo>/",
     ];
 
     let fail = vec![
@@ -214,14 +214,14 @@ fn check_character(
         }
         if unicode_sets {
             if REGEX_CLASS_SET_RESERVED_DOUBLE_PUNCTUATOR.contains(escape_char) {
-                if let Some(prev_char) = source_text.chars().nth(span.end as usize) {
+                if let Some(next_char) = source_text[span.end as usize..].chars().next() {
                     // Escaping is valid when it is a reserved double punctuator
-                    if prev_char == escape_char {
+                    if next_char == escape_char {
                         return None;
                     }
                 }
-                if let Some(prev_prev_char) = source_text.chars().nth(span.start as usize - 1)
-                    && prev_prev_char == escape_char
+                if let Some(prev_char) = source_text[..span.start as usize].chars().next_back()
+                    && prev_char == escape_char
                 {
                     if escape_char != '^' {
                         return None;
@@ -572,6 +572,8 @@ fn test() {
         (r"/[\.&&\.&&\.]/v", Some(serde_json::json!([{ "allowRegexCharacters": ["."] }]))), // { "ecmaVersion": 2024 },
         (r"/[[\.&]--[\.&]]/v", Some(serde_json::json!([{ "allowRegexCharacters": ["."] }]))), // { "ecmaVersion": 2024 },
         (r"/[[\.&]&&[\.&]]/v", Some(serde_json::json!([{ "allowRegexCharacters": ["."] }]))), // { "ecmaVersion": 2024 }
+        ("var a = '😀'; /[\\$$]/v", None),
+        ("var a = '😀'; /[$\\$]/v", None),
     ];
 
     let fail = vec![
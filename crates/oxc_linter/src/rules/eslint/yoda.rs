@@ -1224,3 +1224,10 @@ fn test() {
 
     Tester::new(Yoda::NAME, Yoda::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
 }
+
+#[test]
+fn test_eslint_fixture() {
+    use crate::tester::Tester;
+
+    Tester::from_eslint_fixture(Yoda::NAME, Yoda::PLUGIN, "eslint_rule_tester/yoda.json").test();
+}
@@ -63,6 +63,9 @@ fn test() {
             None,
         ),
         ("import { test } from './test-utils'; test('something');", None),
+        ("import { test as xCheck } from '@jest/globals'; xCheck('foo', () => {});", None),
+        ("import { it as xIt } from '@jest/globals'; xIt.each([])('foo', () => {});", None),
+        ("it.todo('fill this later', function () {})", None),
     ];
 
     let fail = vec![
@@ -92,6 +95,10 @@ fn test() {
         ("pending()", None),
         ("describe('contains a call to pending', function () { pending() })", None),
         ("import { test } from '@jest/globals';test('something');", None),
+        ("it.concurrent.skip('foo', function () {})", None),
+        ("test.concurrent.skip('foo', function () {})", None),
+        ("import { xit as skipped } from '@jest/globals'; skipped('foo', () => {});", None),
+        ("import { describe as suite } from '@jest/globals'; suite.skip('foo', () => {});", None),
     ];
 
     Tester::new(NoDisabledTests::NAME, NoDisabledTests::PLUGIN, pass, fail)
@@ -9,7 +9,7 @@ use crate::{
 #[derive(Debug, Default, Clone)]
 pub struct NoIdenticalTitle;
 
-declare_oxc_lint!(NoIdenticalTitle, jest, style, docs = DOCUMENTATION, version = "0.0.14",);
+declare_oxc_lint!(NoIdenticalTitle, jest, correctness, docs = DOCUMENTATION, version = "0.0.14",);
 
 impl Rule for NoIdenticalTitle {
     fn run_once(&self, ctx: &LintContext) {
@@ -314,6 +314,19 @@ fn test() {
             ",
             None,
         ),
+        (
+            r"
+              describe('foo', () => {
+                it('bar', () => {});
+                describe('baz', () => {
+                  it('bar', () => {});
+                  it(`b\u0061r`, () => {});
+                });
+                it(`bar`, () => {});
+              });
+            ",
+            None,
+        ),
         // (
         //     "
         //       context('foo', () => {
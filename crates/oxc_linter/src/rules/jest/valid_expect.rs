@@ -162,6 +162,8 @@ fn test() {
         ("test('valid-expect', () => { expect(Promise.reject(2)).toRejectWith(2); });", Some(serde_json::json!([{ "asyncMatchers": ["toResolveWith"] }]))),
         ("test('valid-expect', async () => { await expect(Promise.resolve(2)).toResolve(); });", Some(serde_json::json!([{ "asyncMatchers": ["toResolveWith"] }]))),
         ("test('valid-expect', async () => { expect(Promise.resolve(2)).toResolve(); });", Some(serde_json::json!([{ "asyncMatchers": ["toResolveWith"] }]))),
+        ("test('valid-expect', async () => { await expect(value).toMatchSnapshot(); });", None),
+        ("test('valid-expect', async () => { await expect(value).toBeVisible(); });", None),
     ];
 
     let fail = vec![
@@ -410,6 +412,8 @@ fn test() {
             ",
             None,
         ),
+        ("test('valid-expect', async () => { await expect(1).toBe(1); });", None),
+        ("test('valid-expect', async () => { await expect(mock).not.toHaveBeenCalled(); });", None),
     ];
 
     let fix = vec![
@@ -742,6 +746,11 @@ fn test() {
             });"#,
             None,
         ),
+        (
+            "test('valid-expect', async () => { await expect(1).toBe(1); });",
+            "test('valid-expect', async () => { expect(1).toBe(1); });",
+            None,
+        ),
     ];
 
     Tester::new(ValidExpect::NAME, ValidExpect::PLUGIN, pass, fail)
@@ -137,12 +137,16 @@ declare_oxc_lint!(
     /// an element. Missing or inadequate alt text makes content inaccessible
     /// to users who rely on assistive technologies.
     ///
+    /// Use `alt=""` for purely decorative images. Elements with spread props
+    /// (`<img {...props} />`) are not reported for a missing `alt`, since the
+    /// spread may provide it.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
     /// ```jsx
     /// <img src="flower.jpg" />
-    /// <img src="flower.jpg" alt="" />
+    /// <img src="flower.jpg" alt={undefined} />
     /// <object />
     /// <area />
     /// ```
@@ -150,7 +154,7 @@ declare_oxc_lint!(
     /// Examples of **correct** code for this rule:
     /// ```jsx
     /// <img src="flower.jpg" alt="A close-up of a white daisy" />
-    /// <img src="decorative.jpg" alt="" role="presentation" />
+    /// <img src="decorative.jpg" alt="" />
     /// <object aria-label="Interactive chart" />
     /// <area alt="Navigation link" />
     /// ```
@@ -272,6 +276,11 @@ fn aria_label_has_value<'a>(item: &'a JSXAttributeItem<'a>) -> bool {
     }
 }
 
+/// A spread may provide `alt` (or a label) at runtime, so we can't tell it's missing.
+fn has_spread_attribute(node: &JSXOpeningElement<'_>) -> bool {
+    node.attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_)))
+}
+
 fn img_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
     if let Some(alt_prop) = has_jsx_prop_ignore_case(node, "alt") {
         if !is_valid_alt_prop(alt_prop) {
@@ -299,6 +308,10 @@ fn img_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
         return;
     }
 
+    if has_spread_attribute(node) {
+        return;
+    }
+
     ctx.diagnostic(missing_alt_prop(node.span));
 }
 
@@ -316,7 +329,11 @@ fn object_rule<'a>(
         .and_then(get_string_literal_prop_value)
         .is_some_and(|v| !v.is_empty());
 
-    if has_label || has_title_attr || object_has_accessible_child(ctx, parent) {
+    if has_label
+        || has_title_attr
+        || has_spread_attribute(node)
+        || object_has_accessible_child(ctx, parent)
+    {
         return;
     }
     ctx.diagnostic(object(node.span));
@@ -333,7 +350,9 @@ fn area_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<'a>) {
     }
     has_jsx_prop_ignore_case(node, "alt").map_or_else(
         || {
-            ctx.diagnostic(area(node.span));
+            if !has_spread_attribute(node) {
+                ctx.diagnostic(area(node.span));
+            }
         },
         |alt_prop| {
             if !is_valid_alt_prop(alt_prop) {
@@ -354,7 +373,9 @@ fn input_type_image_rule<'a>(node: &'a JSXOpeningElement<'a>, ctx: &LintContext<
     }
     has_jsx_prop_ignore_case(node, "alt").map_or_else(
         || {
-            ctx.diagnostic(input_type_image(node.span));
+            if !has_spread_attribute(node) {
+                ctx.diagnostic(input_type_image(node.span));
+            }
         },
         |alt_prop| {
             if !is_valid_alt_prop(alt_prop) {
@@ -477,6 +498,14 @@ fn test() {
         (r#"<InputImage alt="" />"#, Some(config()), None),
         (r#"<InputImage alt="This is descriptive!" />"#, Some(config()), None),
         (r"<InputImage alt={altText} />", Some(config()), None),
+        (r"<img {...this.props} />", None, None),
+        (r"<area {...this.props} />", None, None),
+        (r#"<input type="image" {...this.props} />"#, None, None),
+        (r"<Thumbnail {...this.props} />", Some(config()), None),
+        (r"<Image {...this.props} />", Some(config()), None),
+        (r"<Area {...this.props} />", Some(config()), None),
+        (r"<InputImage {...this.props} />", Some(config()), None),
+        (r"<object {...this.props} />", None, None),
     ];
 
     let fail = vec![
@@ -485,7 +514,7 @@ fn test() {
         (r"<img alt={undefined} />;", None, None),
         (r#"<img src="xyz" />"#, None, None),
         (r"<img role />", None, None),
-        (r"<img {...this.props} />", None, None),
+        (r"<img {...this.props} alt={undefined} />", None, None),
         // TODO: Could support if get_prop_value could evaluate
         // some logical expressions
         // (r#"<img alt={false || false} />"#, None, None),
@@ -514,8 +543,8 @@ fn test() {
         (r"<area />", None, None),
         (r"<area alt />", None, None),
         (r"<area alt={undefined} />", None, None),
+        (r"<area {...this.props} alt={undefined} />", None, None),
         (r#"<area src="xyz" />"#, None, None),
-        (r"<area {...this.props} />", None, None),
         (r#"<area aria-label="" />"#, None, None),
         (r"<area aria-label={undefined} />", None, None),
         (r#"<area aria-labelledby="" />"#, None, None),
@@ -524,7 +553,6 @@ fn test() {
         (r#"<input type="image" alt />"#, None, None),
         (r#"<input type="image" alt={undefined} />"#, None, None),
         (r#"<input type="image">Foo</input>"#, None, None),
-        (r#"<input type="image" {...this.props} />"#, None, None),
         (r#"<input type="image" aria-label="" />"#, None, None),
         (r#"<input type="image" aria-label={undefined} />"#, None, None),
         (r#"<input type="image" aria-labelledby="" />"#, None, None),
@@ -533,12 +561,10 @@ fn test() {
         (r"<Thumbnail alt />;", Some(config()), None),
         (r"<Thumbnail alt={undefined} />;", Some(config()), None),
         (r#"<Thumbnail src="xyz" />"#, Some(config()), None),
-        (r"<Thumbnail {...this.props} />", Some(config()), None),
         (r"<Image />;", Some(config()), None),
         (r"<Image alt />;", Some(config()), None),
         (r"<Image alt={undefined} />;", Some(config()), None),
         (r#"<Image src="xyz" />"#, Some(config()), None),
-        (r"<Image {...this.props} />", Some(config()), None),
         (r"<Object />", Some(config()), None),
         (r"<Object><div aria-hidden /></Object>", Some(config()), None),
         (r"<Object title={undefined} />", Some(config()), None),
@@ -546,12 +572,10 @@ fn test() {
         (r"<Area alt />", Some(config()), None),
         (r"<Area alt={undefined} />", Some(config()), None),
         (r#"<Area src="xyz" />"#, Some(config()), None),
-        (r"<Area {...this.props} />", Some(config()), None),
         (r"<InputImage />", Some(config()), None),
         (r"<InputImage alt />", Some(config()), None),
         (r"<InputImage alt={undefined} />", Some(config()), None),
         (r"<InputImage>Foo</InputImage>", Some(config()), None),
-        (r"<InputImage {...this.props} />", Some(config()), None),
         (r#"<Input type="image" />"#, None, None),
     ];
 
@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use oxc_ast::{
     AstKind,
-    ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression},
+    ast::{Expression, JSXAttributeItem, JSXAttributeValue},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -251,26 +251,28 @@ impl AnchorIsValid {
             JSXAttributeValue::StringLiteral(str_lit) => {
                 Self::href_value_kind_from_string(&str_lit.value)
             }
-            JSXAttributeValue::ExpressionContainer(exp) => match &exp.expression {
-                JSXExpression::Identifier(ident) if ident.name == "undefined" => {
-                    HrefValueKind::Nullish
-                }
-                JSXExpression::NullLiteral(_) => HrefValueKind::Nullish,
-                JSXExpression::StringLiteral(str_lit) => {
-                    Self::href_value_kind_from_string(&str_lit.value)
-                }
-                JSXExpression::TemplateLiteral(temp_lit) => {
-                    if !temp_lit.expressions.is_empty() {
-                        return HrefValueKind::Valid;
+            JSXAttributeValue::ExpressionContainer(exp) => {
+                let Some(expr) = exp.expression.as_expression() else {
+                    return HrefValueKind::Valid;
+                };
+                match expr.without_parentheses() {
+                    expr if expr.is_null_or_undefined() => HrefValueKind::Nullish,
+                    Expression::StringLiteral(str_lit) => {
+                        Self::href_value_kind_from_string(&str_lit.value)
                     }
+                    Expression::TemplateLiteral(temp_lit) => {
+                        if !temp_lit.expressions.is_empty() {
+                            return HrefValueKind::Valid;
+                        }
 
-                    let Some(quasi) = temp_lit.single_quasi() else {
-                        return HrefValueKind::Valid;
-                    };
-                    Self::href_value_kind_from_string(&quasi)
+                        let Some(quasi) = temp_lit.single_quasi() else {
+                            return HrefValueKind::Valid;
+                        };
+                        Self::href_value_kind_from_string(&quasi)
+                    }
+                    _ => HrefValueKind::Valid,
                 }
-                _ => HrefValueKind::Valid,
-            },
+            }
             JSXAttributeValue::Fragment(_) => HrefValueKind::Nullish,
         }
     }
@@ -579,7 +581,12 @@ fn test() {
         (r"<a href />", None, None),
         (r"<a href={undefined} />", None, None),
         (r"<a href={null} />", None, None),
+        (r"<a href={void 0} />", None, None),
+        (r"<a href={(undefined)} />", None, None),
+        (r"<a href={void 0} onClick={foo} />", None, None),
         (r"<a href='' />;", None, None),
+        (r##"<a href={("#")} />"##, None, None),
+        (r"<a href={('javascript:void(0)')} onClick={foo} />", None, None),
         (r"<a href='#' />", None, None),
         (r"<a href={'#'} />", None, None),
         (r"<a href={`#`} />", None, None),
@@ -124,24 +124,26 @@ impl Rule for AriaRole {
 
             match get_prop_value(aria_role) {
                 Some(JSXAttributeValue::ExpressionContainer(container)) => {
-                    let jsexp = &container.expression;
-                    if matches!(jsexp, JSXExpression::NullLiteral(_)) || jsexp.is_undefined() {
-                        ctx.diagnostic(aria_role_diagnostic(attr.span, ""));
+                    match &container.expression {
+                        JSXExpression::NullLiteral(_) => {
+                            ctx.diagnostic(aria_role_diagnostic(attr.span, ""));
+                        }
+                        jsexp if jsexp.is_undefined() => {
+                            ctx.diagnostic(aria_role_diagnostic(attr.span, ""));
+                        }
+                        JSXExpression::StringLiteral(str) => {
+                            self.check_role_value(str.value.as_str(), str.span, ctx);
+                        }
+                        JSXExpression::TemplateLiteral(template) => {
+                            if let Some(value) = template.single_quasi() {
+                                self.check_role_value(value.as_str(), template.span, ctx);
+                            }
+                        }
+                        _ => {}
                     }
                 }
                 Some(JSXAttributeValue::StringLiteral(str)) => {
-                    let value = str.value.as_str();
-                    if value.trim().is_empty() {
-                        ctx.diagnostic(aria_role_diagnostic(str.span, ""));
-                    } else if let Some(error_prop) = value.split_whitespace().find(|word| {
-                        !VALID_ARIA_ROLES.contains(word)
-                            && !self.allowed_invalid_roles.iter().any(|s| s == word)
-                    }) {
-                        ctx.diagnostic(aria_role_diagnostic(
-                            str.span,
-                            &format!(", `{error_prop}` is an invalid aria role"),
-                        ));
-                    }
+                    self.check_role_value(str.value.as_str(), str.span, ctx);
                 }
                 _ => {
                     ctx.diagnostic(aria_role_diagnostic(attr.span, ""));
@@ -151,6 +153,22 @@ impl Rule for AriaRole {
     }
 }
 
+impl AriaRole {
+    fn check_role_value(&self, value: &str, span: Span, ctx: &LintContext<'_>) {
+        if value.trim().is_empty() {
+            ctx.diagnostic(aria_role_diagnostic(span, ""));
+        } else if let Some(error_prop) = value.split_whitespace().find(|word| {
+            !VALID_ARIA_ROLES.contains(word)
+                && !self.allowed_invalid_roles.iter().any(|s| s == word)
+        }) {
+            ctx.diagnostic(aria_role_diagnostic(
+                span,
+                &format!(", `{error_prop}` is an invalid aria role"),
+            ));
+        }
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -198,6 +216,9 @@ fn test() {
         ("<Div role='button' />", None, Some(settings())),
         ("<Box asChild='div' role='button' />", None, Some(settings())),
         ("<svg role='graphics-document document' />", None, None),
+        ("<div role={'button'} />", None, None),
+        ("<div role={`tabpanel row`} />", None, None),
+        ("<div role={`${role}`} />", None, None),
     ];
 
     let fail = vec![
@@ -217,6 +238,15 @@ fn test() {
         ("<Div role='Button' />", None, Some(settings())),
         ("<Div role='Button' />", Some(ignore_non_dom_schema()), Some(settings())),
         ("<Box asChild='div' role='Button' />", None, None),
+        ("<div role={'foobar'} />", None, None),
+        ("<div role={''} />", None, None),
+        ("<div role={`range`} />", None, None),
+        ("<div role={undefined} />", None, None),
+        // Abstract roles
+        ("<div role='command' />", None, None),
+        ("<div role='widget' />", None, None),
+        ("<div role='landmark' />", None, None),
+        ("<div role='roletype' />", None, None),
     ];
 
     Tester::new(AriaRole::NAME, AriaRole::PLUGIN, pass, fail).test_and_snapshot();
@@ -4,7 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -110,18 +110,42 @@ impl Rule for NoAutofocus {
 
             if HTML_TAG.contains(element_type.as_ref()) {
                 ctx.diagnostic_with_suggestion(no_autofocus_diagnostic(attr.span), |fixer| {
-                    fixer.delete(&attr.span)
+                    fixer.delete_range(removal_span(ctx, &jsx_el.opening_element, attr.span))
                 });
             }
             return;
         }
 
         ctx.diagnostic_with_suggestion(no_autofocus_diagnostic(attr.span), |fixer| {
-            fixer.delete(&attr.span)
+            fixer.delete_range(removal_span(ctx, &jsx_el.opening_element, attr.span))
         });
     }
 }
 
+/// Span to delete to remove the attribute at `attr_span`, including the whitespace before it.
+fn removal_span(ctx: &LintContext<'_>, opening: &JSXOpeningElement<'_>, attr_span: Span) -> Span {
+    let prev_end = opening
+        .attributes
+        .iter()
+        .map(GetSpan::span)
+        .take_while(|span| span.end <= attr_span.start)
+        .last()
+        .map_or_else(
+            || {
+                opening
+                    .type_arguments
+                    .as_ref()
+                    .map_or(opening.name.span().end, |type_args| type_args.span.end)
+            },
+            |span| span.end,
+        );
+    let gap = ctx.source_range(Span::new(prev_end, attr_span.start));
+    // Keep anything other than whitespace, e.g. comments
+    #[expect(clippy::cast_possible_truncation)]
+    let start = prev_end + gap.trim_end().len() as u32;
+    Span::new(start, attr_span.end)
+}
+
 fn is_dialog_or_popover<'a>(ctx: &LintContext<'a>, opening: &JSXOpeningElement<'a>) -> bool {
     if get_element_type(ctx, opening) == "dialog" {
         return true;
@@ -239,13 +263,18 @@ fn test() {
     ];
 
     let fix = vec![
-        ("<div autoFocus />", "<div  />"),
-        ("<div autoFocus={true} />", "<div  />"),
-        ("<div autoFocus='true' />", "<div  />"),
-        ("<Button autoFocus='true' />", "<Button  />"),
-        ("<input autoFocus />", "<input  />"),
-        ("<div autoFocus>foo</div>", "<div >foo</div>"),
-        ("<div autoFocus id='lol'>foo</div>", "<div  id='lol'>foo</div>"),
+        ("<div autoFocus />", "<div />"),
+        ("<div autoFocus={true} />", "<div />"),
+        ("<div autoFocus='true' />", "<div />"),
+        ("<Button autoFocus='true' />", "<Button />"),
+        ("<input autoFocus />", "<input />"),
+        ("<div autoFocus>foo</div>", "<div>foo</div>"),
+        ("<div autoFocus id='lol'>foo</div>", "<div id='lol'>foo</div>"),
+        ("<div id='lol' autoFocus>foo</div>", "<div id='lol'>foo</div>"),
+        ("<div\n  id='lol'\n  autoFocus\n/>", "<div\n  id='lol'\n/>"),
+        ("<Foo<T> autoFocus />", "<Foo<T> />"),
+        ("<div /* comment */ autoFocus />", "<div /* comment */ />"),
+        ("<div /* comment */autoFocus />", "<div /* comment */ />"),
     ];
 
     Tester::new(NoAutofocus::NAME, NoAutofocus::PLUGIN, pass, fail)
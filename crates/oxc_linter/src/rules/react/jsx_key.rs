@@ -126,11 +126,16 @@ impl Rule for JsxKey {
                     check_jsx_element_is_key_before_spread(jsx_elem, ctx);
                 }
                 if self.warn_on_duplicates {
-                    check_duplicate_keys_in_children(jsx_elem, ctx);
+                    check_duplicate_keys_in_children(&jsx_elem.children, ctx);
                 }
             }
-            AstKind::JSXFragment(jsx_frag) if self.check_fragment_shorthand => {
-                check_jsx_fragment(node, jsx_frag, ctx);
+            AstKind::JSXFragment(jsx_frag) => {
+                if self.check_fragment_shorthand {
+                    check_jsx_fragment(node, jsx_frag, ctx);
+                }
+                if self.warn_on_duplicates {
+                    check_duplicate_keys_in_children(&jsx_frag.children, ctx);
+                }
             }
             AstKind::ArrayExpression(array_expr) if self.warn_on_duplicates => {
                 check_duplicate_keys_in_array(array_expr, ctx);
@@ -443,10 +448,10 @@ fn check_duplicate_keys_in_array<'a>(array_expr: &ArrayExpression<'a>, ctx: &Lin
     }
 }
 
-fn check_duplicate_keys_in_children<'a>(jsx_elem: &JSXElement<'a>, ctx: &LintContext<'a>) {
+fn check_duplicate_keys_in_children<'a>(children: &[JSXChild<'a>], ctx: &LintContext<'a>) {
     let mut seen_keys: FxHashSet<String> = FxHashSet::default();
 
-    for child in &jsx_elem.children {
+    for child in children {
         if let JSXChild::Element(child_elem) = child
             && let Some((key_value, span)) = get_jsx_element_key_value(child_elem)
             && !seen_keys.insert(key_value.clone())
@@ -481,6 +486,7 @@ fn test() {
         (r"foo(() => <div />);", None, None),
         (r"foo(() => <></>);", None, None),
         (r"<></>;", None, None),
+        (r#"<><span key="a" /><span key="b" /></>;"#, Some(serde_json::json!([{ "warnOnDuplicates": true }])), None),
         (r"<App {...{}} />;", None, None),
         (r#"<App key="keyBeforeSpread" {...{}} />;"#, Some(serde_json::json!([{ "checkKeyMustBeforeSpread": true }])), None),
         (r#"<div key="keyBeforeSpread" {...{}} />;"#, Some(serde_json::json!([{ "checkKeyMustBeforeSpread": true }])), None),
@@ -666,6 +672,18 @@ fn test() {
             Some(serde_json::json!([{ "warnOnDuplicates": true }])),
             None,
         ),
+        (
+            r#"
+                    const fragment = (
+                      <>
+                        <span key="notunique"/>
+                        <span key="notunique"/>
+                      </>
+                    );
+                  "#,
+            Some(serde_json::json!([{ "warnOnDuplicates": true }])),
+            None,
+        ),
         (
             "
                     const Test = () => {
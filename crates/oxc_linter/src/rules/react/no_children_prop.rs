@@ -1,6 +1,6 @@
 use oxc_ast::{
     AstKind,
-    ast::{Argument, JSXAttributeName, ObjectPropertyKind},
+    ast::{Argument, JSXAttribute, JSXAttributeName, JSXAttributeValue, ObjectPropertyKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -55,6 +55,7 @@ declare_oxc_lint!(
     NoChildrenProp,
     react,
     correctness,
+    suggestion,
     version = "0.0.14",
     short_description = "Checks that children are not passed using a prop.",
 );
@@ -66,8 +67,16 @@ impl Rule for NoChildrenProp {
                 let JSXAttributeName::Identifier(attr_ident) = &attr.name else {
                     return;
                 };
-                if attr_ident.name == "children" {
-                    ctx.diagnostic(no_children_prop_diagnostic(attr_ident.span));
+                if attr_ident.name != "children" {
+                    return;
+                }
+                let diagnostic = no_children_prop_diagnostic(attr_ident.span);
+                if let Some((span, replacement)) = move_children_into_element(node, attr, ctx) {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        fixer.replace(span, replacement)
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
                 }
             }
             AstKind::CallExpression(call_expr) => {
@@ -91,6 +100,45 @@ impl Rule for NoChildrenProp {
     }
 }
 
+/// Get a replacement which moves the value of a `children` attribute of a self-closing element into the element,
+/// e.g. `<Foo children={bar} />` to `<Foo>{bar}</Foo>`.
+fn move_children_into_element<'a>(
+    node: &AstNode<'a>,
+    attr: &JSXAttribute<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Span, String)> {
+    let AstKind::JSXOpeningElement(opening) = ctx.nodes().parent_kind(node.id()) else {
+        return None;
+    };
+    let AstKind::JSXElement(element) = ctx.nodes().parent_kind(ctx.nodes().parent_id(node.id()))
+    else {
+        return None;
+    };
+    if element.closing_element.is_some() {
+        return None;
+    }
+
+    let children = match attr.value.as_ref()? {
+        JSXAttributeValue::StringLiteral(lit) => {
+            let raw = ctx.source_range(lit.span);
+            // HTML entities and backslashes are not decoded the same way in a JS string,
+            // and line breaks are not allowed in one
+            if raw.contains(['&', '\\', '\n', '\r']) {
+                return None;
+            }
+            format!("{{{raw}}}")
+        }
+        value => ctx.source_range(value.span()).to_string(),
+    };
+
+    let last_attr_end = opening.attributes.last().map_or(attr.span.end, |attr| attr.span().end);
+    let before_attr = ctx.source_range(Span::new(opening.span.start, attr.span.start)).trim_end();
+    let after_attr = ctx.source_range(Span::new(attr.span.end, last_attr_end));
+    let name = ctx.source_range(opening.name.span());
+
+    Some((element.span, format!("{before_attr}{after_attr}>{children}</{name}>")))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -156,5 +204,29 @@ fn test() {
         r#"React.createElement(MyComponent, {...props, children: "Children"})"#,
     ];
 
-    Tester::new(NoChildrenProp::NAME, NoChildrenProp::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"<div children="Children" />;"#, r#"<div>{"Children"}</div>;"#),
+        ("<div children={<div />} />;", "<div>{<div />}</div>;"),
+        ("<div children=<div /> />;", "<div><div /></div>;"),
+        (
+            r#"<MyComponent className="class-name" children={foo} id="x" />;"#,
+            r#"<MyComponent className="class-name" id="x">{foo}</MyComponent>;"#,
+        ),
+        ("<Foo.Bar<T> children={foo}\n/>;", "<Foo.Bar<T>>{foo}</Foo.Bar>;"),
+        ("<div children={foo} title='a/b' />;", "<div title='a/b'>{foo}</div>;"),
+        // No suggestion if there is no value, the value has entities, backslashes or line
+        // breaks, or the element already has a closing tag
+        ("<div children />;", "<div children />;"),
+        (r#"<div children="a&amp;b" />;"#, r#"<div children="a&amp;b" />;"#),
+        (r#"<div children="a\b" />;"#, r#"<div children="a\b" />;"#),
+        ("<div children=\"a\nb\" />;", "<div children=\"a\nb\" />;"),
+        (
+            r#"<div children="Children">Children</div>;"#,
+            r#"<div children="Children">Children</div>;"#,
+        ),
+    ];
+
+    Tester::new(NoChildrenProp::NAME, NoChildrenProp::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
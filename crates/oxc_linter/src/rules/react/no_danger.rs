@@ -1,16 +1,20 @@
+use fast_glob::glob_match;
 use oxc_ast::{
     AstKind,
-    ast::{Argument, JSXAttributeItem, ObjectPropertyKind},
+    ast::{Argument, Expression, JSXAttributeItem, ObjectPropertyKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::{
     AstNode,
     context::{ContextHost, LintContext},
-    rule::Rule,
-    utils::{has_jsx_prop, is_create_element_call},
+    rule::{DefaultRuleConfig, Rule},
+    utils::{get_jsx_element_name, has_jsx_prop, is_create_element_call},
 };
 
 fn no_danger_diagnostic(span: Span) -> OxcDiagnostic {
@@ -19,8 +23,24 @@ fn no_danger_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct NoDanger;
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDanger(Box<NoDangerConfig>);
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDangerConfig {
+    /// Names of custom components to check, in addition to DOM elements.
+    /// Glob patterns are supported, e.g. `["*"]` checks all components.
+    pub custom_component_names: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoDanger {
+    type Target = NoDangerConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -48,17 +68,34 @@ declare_oxc_lint!(
     ///
     /// const Hello = <div>Hello World</div>;
     /// ```
+    ///
+    /// Only DOM elements (e.g. `<div>`) are checked by default. Use the `customComponentNames`
+    /// option to also check custom components.
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "customComponentNames": ["Html*"] }`:
+    /// ```jsx
+    /// const Hello = <HtmlContent dangerouslySetInnerHTML={{ __html: "Hello World" }} />;
+    /// ```
     NoDanger,
     react,
-    restriction,
+    suspicious,
+    config = NoDangerConfig,
     version = "0.0.14",
     short_description = "This rule prevents the use of `dangerouslySetInnerHTML` prop.",
 );
 
 impl Rule for NoDanger {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
+                let name = get_jsx_element_name(&jsx_elem.opening_element.name);
+                if !self.should_check_element(&name) {
+                    return;
+                }
                 if let Some(JSXAttributeItem::Attribute(prop)) =
                     has_jsx_prop(&jsx_elem.opening_element, "dangerouslySetInnerHTML")
                 {
@@ -70,6 +107,17 @@ impl Rule for NoDanger {
                     return;
                 }
 
+                let should_check = match call_expr.arguments.first() {
+                    Some(Argument::StringLiteral(lit)) => self.should_check_element(&lit.value),
+                    Some(arg) => arg.as_expression().is_some_and(|expr| {
+                        component_name(expr).is_some_and(|name| self.is_custom_component(&name))
+                    }),
+                    None => false,
+                };
+                if !should_check {
+                    return;
+                }
+
                 let Some(props) = call_expr.arguments.get(1) else {
                     return;
                 };
@@ -96,21 +144,80 @@ impl Rule for NoDanger {
     }
 }
 
+impl NoDanger {
+    /// DOM elements are always checked. Other components only if listed in `customComponentNames`.
+    fn should_check_element(&self, name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_lowercase()) || self.is_custom_component(name)
+    }
+
+    fn is_custom_component(&self, name: &str) -> bool {
+        self.custom_component_names.iter().any(|pattern| glob_match(pattern.as_str(), name))
+    }
+}
+
+/// Get name of a component passed to `React.createElement`, e.g. `Foo` or `Foo.Bar`.
+fn component_name(expr: &Expression) -> Option<String> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        Expression::StaticMemberExpression(member) => {
+            let object = component_name(&member.object)?;
+            Some(format!("{object}.{}", member.property.name))
+        }
+        _ => None,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "<App />;",
-        "<div className=\"bar\"></div>;",
-        "React.createElement(\"div\", { className: \"bar\" });",
+        ("<App />;", None),
+        ("<div className=\"bar\"></div>;", None),
+        ("React.createElement(\"div\", { className: \"bar\" });", None),
+        ("<App dangerouslySetInnerHTML={{ __html: \"\" }} />;", None),
+        ("<Foo.Bar dangerouslySetInnerHTML={{ __html: \"\" }} />;", None),
+        ("React.createElement(App, { dangerouslySetInnerHTML: { __html: \"\" } });", None),
+        (
+            "<App dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
+        (
+            "<Title className=\"bar\" />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
     ];
 
     let fail = vec![
-        "<div dangerouslySetInnerHTML={{ __html: \"\" }}></div>;",
-        "<button dangerouslySetInnerHTML={{ __html: \"baz\" }}>Foo</button>;",
-        "React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"\" } });",
-        "React.createElement(\"button\", { dangerouslySetInnerHTML: { __html: \"baz\" } }, \"Foo\");",
+        ("<div dangerouslySetInnerHTML={{ __html: \"\" }}></div>;", None),
+        ("<button dangerouslySetInnerHTML={{ __html: \"baz\" }}>Foo</button>;", None),
+        ("<div dangerouslySetInnerHTML={content} />;", None),
+        ("<div dangerouslySetInnerHTML />;", None),
+        ("React.createElement(\"div\", { dangerouslySetInnerHTML: { __html: \"\" } });", None),
+        (
+            "React.createElement(\"button\", { dangerouslySetInnerHTML: { __html: \"baz\" } }, \"Foo\");",
+            None,
+        ),
+        (
+            "<App dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["*"] }])),
+        ),
+        (
+            "<Title dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
+        (
+            "<HtmlContent dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Html*"] }])),
+        ),
+        (
+            "<Foo.Bar dangerouslySetInnerHTML={{ __html: \"\" }} />;",
+            Some(serde_json::json!([{ "customComponentNames": ["Foo.*"] }])),
+        ),
+        (
+            "React.createElement(Title, { dangerouslySetInnerHTML: { __html: \"\" } });",
+            Some(serde_json::json!([{ "customComponentNames": ["Title"] }])),
+        ),
     ];
 
     Tester::new(NoDanger::NAME, NoDanger::PLUGIN, pass, fail).test_and_snapshot();
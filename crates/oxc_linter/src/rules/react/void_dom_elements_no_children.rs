@@ -1,9 +1,6 @@
 use oxc_ast::{
     AstKind,
-    ast::{
-        Argument, JSXAttributeItem, JSXAttributeName, JSXElementName, ObjectPropertyKind,
-        PropertyKey,
-    },
+    ast::{Argument, JSXAttributeItem, JSXAttributeName, JSXElementName, ObjectPropertyKind},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -122,26 +119,22 @@ impl Rule for VoidDomElementsNoChildren {
                     return;
                 }
 
-                if call_expr.arguments.len() < 2 {
-                    return;
-                }
-
-                let Some(Argument::ObjectExpression(obj_expr)) = call_expr.arguments.get(1) else {
-                    return;
-                };
+                // Children passed as arguments, e.g. `React.createElement('br', null, 'Foo')`
+                let has_children_arguments = call_expr.arguments.len() > 2;
 
-                let has_children_prop_or_danger =
-                    obj_expr.properties.iter().any(|property| match property {
-                        ObjectPropertyKind::ObjectProperty(prop) => match &prop.key {
-                            PropertyKey::StaticIdentifier(iden) => {
-                                iden.name == "children" || iden.name == "dangerouslySetInnerHTML"
+                let has_children_prop_or_danger = matches!(
+                    call_expr.arguments.get(1),
+                    Some(Argument::ObjectExpression(obj_expr))
+                        if obj_expr.properties.iter().any(|property| match property {
+                            ObjectPropertyKind::ObjectProperty(prop) => {
+                                prop.key.is_specific_static_name("children")
+                                    || prop.key.is_specific_static_name("dangerouslySetInnerHTML")
                             }
-                            _ => false,
-                        },
-                        ObjectPropertyKind::SpreadProperty(_) => false,
-                    });
+                            ObjectPropertyKind::SpreadProperty(_) => false,
+                        })
+                );
 
-                if call_expr.arguments.get(2).is_some() || has_children_prop_or_danger {
+                if has_children_arguments || has_children_prop_or_danger {
                     ctx.diagnostic(void_dom_elements_no_children_diagnostic(
                         &element_name.value,
                         element_name.span,
@@ -169,6 +162,8 @@ fn test() {
         (r"React.createElement('div', { children: 'Foo' });", None),
         (r"React.createElement('div', { dangerouslySetInnerHTML: { __html: 'Foo' } });", None),
         (r"React.createElement('img');", None),
+        (r"React.createElement('img', null);", None),
+        (r"React.createElement('img', { [children]: 'Foo' });", None),
         (r"React.createElement();", None),
         (
             r"
@@ -215,6 +210,12 @@ fn test() {
         (r"React.createElement('br', {}, 'Foo');", None),
         (r"React.createElement('br', { children: 'Foo' });", None),
         (r"React.createElement('br', { dangerouslySetInnerHTML: { __html: 'Foo' } });", None),
+        (r"React.createElement('br', null, 'Foo');", None),
+        (r"React.createElement('br', undefined, 'Foo', 'Bar');", None),
+        (r"React.createElement('br', props, React.createElement('span'));", None),
+        (r"React.createElement('br', { 'children': 'Foo' });", None),
+        (r"<hr><span>Foo</span></hr>;", None),
+        (r"<input>{value}</input>;", None),
         (
             r"
                 import React, {createElement} from 'react';
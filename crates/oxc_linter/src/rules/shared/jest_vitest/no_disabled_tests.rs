@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use oxc_ast::{AstKind, ast::Expression};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
@@ -45,7 +47,11 @@ it('foo', () => {
 ```
 ";
 
-fn no_disabled_tests_diagnostic(x1: &'static str, x2: &'static str, span3: Span) -> OxcDiagnostic {
+fn no_disabled_tests_diagnostic(
+    x1: &'static str,
+    x2: impl Into<Cow<'static, str>>,
+    span3: Span,
+) -> OxcDiagnostic {
     OxcDiagnostic::warn(x1).with_help(x2).with_label(span3)
 }
 
@@ -78,7 +84,7 @@ pub fn run_on_jest_node<'a, 'c>(
     let node = possible_jest_node.node;
     if let AstKind::CallExpression(call_expr) = node.kind() {
         if let Some(jest_fn_call) = parse_general_jest_fn_call(call_expr, possible_jest_node, ctx) {
-            let ParsedGeneralJestFnCall { kind, members, name, .. } = jest_fn_call;
+            let ParsedGeneralJestFnCall { kind, members, name, local } = jest_fn_call;
             // `test('foo')`
             let kind = match kind {
                 JestFnKind::Expect
@@ -98,12 +104,20 @@ pub fn run_on_jest_node<'a, 'c>(
 
             // the only jest functions that are with "x" are "xdescribe", "xtest", and "xit"
             // `xdescribe('foo', () => {})`
-            if name.starts_with('x') {
+            if let Some(enabled_name) = name.strip_prefix('x') {
                 let (error, help) = if matches!(kind, JestGeneralFnKind::Describe) {
                     Message::DisabledSuiteWithX.details()
                 } else {
                     Message::DisabledTestWithX.details()
                 };
+                // `import { xit as skipped } from '@jest/globals'; skipped('foo', () => {})`
+                let help = if local == name {
+                    Cow::Borrowed(help)
+                } else {
+                    Cow::Owned(format!(
+                        "`{local}` is an alias of `{name}`, use `{enabled_name}` instead"
+                    ))
+                };
                 ctx.diagnostic(no_disabled_tests_diagnostic(error, help, call_expr.callee.span()));
                 return;
             }
@@ -211,6 +211,13 @@ impl PreferToBe {
     }
 
     fn should_skip_float(expr: &Expression, ctx: &LintContext) -> bool {
+        // `-0.3`
+        let expr = match expr {
+            Expression::UnaryExpression(unary_expr) if unary_expr.operator.as_str() == "-" => {
+                &unary_expr.argument
+            }
+            _ => expr,
+        };
         // Check if this is a float literal by examining the source text
         if let Expression::NumericLiteral(num) = expr {
             let source = ctx.source_range(num.span);
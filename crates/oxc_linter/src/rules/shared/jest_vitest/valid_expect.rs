@@ -41,6 +41,7 @@ expect();
 expect('something');
 expect(true).toBeDefined;
 expect(Promise.resolve('Hi!')).resolves.toBe('Hi!');
+await expect(1).toBe(1);
 ```
 
 Examples of **correct** code for this rule:
@@ -208,7 +209,31 @@ impl ValidExpectConfig {
             jest_fn_call.modifiers().iter().any(|modifier| modifier.is_name_unequal("not"))
                 || self.async_matchers.contains(&matcher_name.to_string());
 
-        if matches!(parent.kind(), AstKind::Program(_)) || !should_be_awaited {
+        if !should_be_awaited {
+            // `await expect(1).toBe(1)`
+            // Custom matchers and `expect.poll(...)` may return a promise, so only built-in
+            // matchers on a plain `expect(...)` are checked.
+            if let AstKind::AwaitExpression(await_expr) = parent.kind()
+                && matches!(call_expr.callee, Expression::Identifier(_))
+                && SYNC_MATCHERS.contains(&matcher_name.as_ref())
+            {
+                let (error, help) = Message::SyncAssertionAwaited.details();
+                ctx.diagnostic_with_suggestion(
+                    valid_expect_diagnostic(error, help, await_expr.span),
+                    |fixer| {
+                        fixer
+                            .delete_range(Span::new(
+                                await_expr.span.start,
+                                await_expr.argument.span().start,
+                            ))
+                            .with_message("Remove `await`.")
+                    },
+                );
+            }
+            return;
+        }
+
+        if matches!(parent.kind(), AstKind::Program(_)) {
             return;
         }
 
@@ -314,6 +339,43 @@ impl ValidExpectConfig {
     }
 }
 
+/// Built-in matchers which never return a promise.
+const SYNC_MATCHERS: [&str; 33] = [
+    "toBe",
+    "toBeCloseTo",
+    "toBeDefined",
+    "toBeFalsy",
+    "toBeGreaterThan",
+    "toBeGreaterThanOrEqual",
+    "toBeInstanceOf",
+    "toBeLessThan",
+    "toBeLessThanOrEqual",
+    "toBeNaN",
+    "toBeNull",
+    "toBeTruthy",
+    "toBeUndefined",
+    "toContain",
+    "toContainEqual",
+    "toEqual",
+    "toHaveBeenCalled",
+    "toHaveBeenCalledTimes",
+    "toHaveBeenCalledWith",
+    "toHaveBeenLastCalledWith",
+    "toHaveBeenNthCalledWith",
+    "toHaveLastReturnedWith",
+    "toHaveLength",
+    "toHaveNthReturnedWith",
+    "toHaveProperty",
+    "toHaveReturned",
+    "toHaveReturnedTimes",
+    "toHaveReturnedWith",
+    "toMatch",
+    "toMatchObject",
+    "toStrictEqual",
+    "toThrow",
+    "toThrowError",
+];
+
 fn find_top_most_member_expression<'a, 'b>(
     node: &'b AstNode<'a>,
     ctx: &'b LintContext<'a>,
@@ -479,6 +541,7 @@ enum Message {
     ModifierUnknown,
     AsyncMustBeAwaited,
     PromisesWithAsyncAssertionsMustBeAwaited,
+    SyncAssertionAwaited,
 }
 
 impl Message {
@@ -502,6 +565,10 @@ impl Message {
                 "Promises which return async assertions must be awaited.",
                 "Add `await` to your assertion.",
             ),
+            Self::SyncAssertionAwaited => (
+                "Non-async assertions should not be awaited.",
+                "Remove `await`, this matcher does not return a promise.",
+            ),
         }
     }
 }
@@ -220,8 +220,10 @@ impl Rule for ArrayType {
                 );
             }
             AstKind::TSTypeReference(ts_type_reference)
-                if ts_type_reference.type_name.get_identifier_reference().is_some_and(
-                    |type_name| matches!(type_name.name.as_str(), "Array" | "ReadonlyArray"),
+                if matches!(
+                    &ts_type_reference.type_name,
+                    TSTypeName::IdentifierReference(type_name)
+                        if matches!(type_name.name.as_str(), "Array" | "ReadonlyArray")
                 ) =>
             {
                 let readonly_config = self.readonly_config();
@@ -506,29 +508,18 @@ fn is_simple_type(ts_type: &TSType) -> bool {
         | TSType::TSArrayType(_)
         | TSType::TSUndefinedKeyword(_)
         | TSType::TSThisType(_) => true,
-        TSType::TSTypeReference(node) => {
-            if let Some(type_name) = TSTypeName::get_identifier_reference(&node.type_name) {
-                if type_name.name.as_str() == "Array" {
-                    if node.type_arguments.is_none() {
-                        return true;
-                    }
-                    if node.type_arguments.as_ref().unwrap().params.len() == 1 {
-                        return is_simple_type(
-                            node.type_arguments.as_ref().unwrap().params.first().unwrap(),
-                        );
-                    }
-                } else {
-                    if node.type_arguments.is_some() {
-                        return false;
-                    }
-                    if node.type_name.is_identifier() || node.type_name.is_qualified_name() {
-                        return true;
-                    }
-                    return false;
-                }
+        TSType::TSTypeReference(node) => match &node.type_name {
+            TSTypeName::IdentifierReference(type_name) if type_name.name == "Array" => {
+                let Some(type_arguments) = &node.type_arguments else {
+                    return true;
+                };
+                type_arguments.params.len() == 1 && is_simple_type(&type_arguments.params[0])
             }
-            false
-        }
+            TSTypeName::IdentifierReference(_) | TSTypeName::QualifiedName(_) => {
+                node.type_arguments.is_none()
+            }
+            TSTypeName::ThisExpression(_) => false,
+        },
         _ => false,
     }
 }
@@ -983,6 +974,12 @@ const instance = new MyClass<number>(42);",
         // https://github.com/oxc-project/oxc/issues/12605
         ("let a: factories.User[] = [];", Some(serde_json::json!([{"default":"array-simple"}]))),
         ("let a: factories.TT.User[] = [];", Some(serde_json::json!([{"default":"array-simple"}]))),
+        ("let a: Array.Foo[] = [];", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "let a: Array<Array.Foo<string>> = [];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
+        ("let a: Array.Foo<string> = [];", Some(serde_json::json!([{"default":"array"}]))),
         (
             "let z: readonly factories.User[] = [];",
             Some(serde_json::json!([{"readonly":"array-simple"}])),
@@ -1001,6 +998,7 @@ const instance = new MyClass<number>(42);",
     let fail = vec![
         ("let a: factories.User[] = [];", Some(serde_json::json!([{"default":"generic"}]))),
         ("let a: Array<number> = [];", Some(serde_json::json!([{"default":"array"}]))),
+        ("let a: Array.Foo<string>[] = [];", Some(serde_json::json!([{"default":"array-simple"}]))),
         ("let a: Array<string | number> = [];", Some(serde_json::json!([{"default":"array"}]))),
         ("let a: ReadonlyArray<number> = [];", Some(serde_json::json!([{"default":"array"}]))),
         (
@@ -1548,6 +1546,11 @@ export const test8 = testFn<Array<string>, number[]>([]);",
     ];
 
     let fix: Vec<(&str, &str, Option<serde_json::Value>)> = vec![
+        (
+            "let a: Array.Foo<string>[] = [];",
+            "let a: Array<Array.Foo<string>> = [];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         (
             "let a: Array<number> = [];",
             "let a: number[] = [];",
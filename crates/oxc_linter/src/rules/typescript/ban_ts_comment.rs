@@ -4,6 +4,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use schemars::JsonSchema;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     context::{ContextHost, LintContext},
@@ -180,7 +181,7 @@ declare_oxc_lint!(
     BanTsComment,
     typescript,
     pedantic,
-    conditional_fix,
+    conditional_suggestion,
     config = BanTsCommentConfig,
     version = "0.0.8",
     short_description = "This rule lets you set which directive comments you want to allow in your codebase.",
@@ -235,7 +236,9 @@ impl Rule for BanTsComment {
                     DirectiveConfig::Boolean(on) => {
                         if *on {
                             if directive == "ignore" {
-                                ctx.diagnostic_with_fix(
+                                // `@ts-expect-error` errors when the next line has no error,
+                                // so this can't be applied automatically.
+                                ctx.diagnostic_with_suggestion(
                                     ignore_instead_of_expect_error(comm.content_span()),
                                     |fixer| {
                                         fixer.replace(
@@ -251,7 +254,7 @@ impl Rule for BanTsComment {
                         }
                     }
                     config => {
-                        let description_len = description.trim().len();
+                        let description_len = description.trim().graphemes(true).count();
                         if (description_len as u64) < self.minimum_description_length {
                             ctx.diagnostic(comment_requires_description(
                                 directive,
@@ -342,7 +345,7 @@ pub fn find_ts_comment_directive(raw: &str, single_line: bool) -> Option<(&str,
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{fixer::FixKind, tester::Tester};
     // A total of 51 test cases passed successfully.
     let pass = vec![
         // ts-expect-error
@@ -708,6 +711,10 @@ fn test() {
         // ts-expect-error
         ("// @ts-expect-error", Some(serde_json::json!([{ "ts-expect-error": true }]))),
         ("/* @ts-expect-error */", Some(serde_json::json!([{ "ts-expect-error": true}]))),
+        (
+            "// @ts-expect-error 👨‍👩‍👧‍👦",
+            Some(serde_json::json!([{ "ts-expect-error": "allow-with-description" }])),
+        ),
         (
             r"
 /*
@@ -1040,11 +1047,23 @@ if (false) {
     ];
 
     let fix = vec![
-        ("// @ts-ignore", r"// @ts-expect-error"),
-        ("/* @ts-ignore */", r"/* @ts-expect-error */"),
-        ("// @ts-ignore: TS1234 because xyz", r"// @ts-expect-error: TS1234 because xyz"),
-        ("// @ts-ignore: TS1234", r"// @ts-expect-error: TS1234"),
-        ("// @ts-ignore    : TS1234 because xyz", r"// @ts-expect-error    : TS1234 because xyz"),
+        ("// @ts-ignore", r"// @ts-expect-error", None, FixKind::Suggestion),
+        ("/* @ts-ignore */", r"/* @ts-expect-error */", None, FixKind::Suggestion),
+        (
+            "// @ts-ignore: TS1234 because xyz",
+            r"// @ts-expect-error: TS1234 because xyz",
+            None,
+            FixKind::Suggestion,
+        ),
+        ("// @ts-ignore: TS1234", r"// @ts-expect-error: TS1234", None, FixKind::Suggestion),
+        (
+            "// @ts-ignore    : TS1234 because xyz",
+            r"// @ts-expect-error    : TS1234 because xyz",
+            None,
+            FixKind::Suggestion,
+        ),
+        // Not applied as a safe fix.
+        ("// @ts-ignore", r"// @ts-ignore", None, FixKind::SafeFix),
     ];
 
     Tester::new(BanTsComment::NAME, BanTsComment::PLUGIN, pass, fail)
@@ -997,24 +997,23 @@ fn test() {
                   ",
             None,
         ),
-        // TODO: Need fix: https://github.com/oxc-project/oxc/issues/3799
-        // (
-        //     "
-        //       import Foo from 'foo';
-        //       type T<Foo> = Foo; // shadowing
-        //     ",
-        //     None,
-        // ),
-        // (
-        //     "
-        //       import Foo from 'foo';
-        //       function fn() {
-        //         type Foo = {}; // shadowing
-        //         let foo: Foo;
-        //       }
-        //     ",
-        //     None,
-        // ),
+        (
+            "
+              import Foo from 'foo';
+              type T<Foo> = Foo; // shadowing
+            ",
+            None,
+        ),
+        (
+            "
+              import Foo from 'foo';
+              function fn() {
+                type Foo = {}; // shadowing
+                let foo: Foo;
+              }
+            ",
+            None,
+        ),
         (
             "
               import { A, B } from 'foo';
@@ -1049,60 +1048,60 @@ fn test() {
             ",
             Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
         ),
-        // (
-        //     "
-        //       import type Type from 'foo';
+        (
+            "
+              import type Type from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     None,
-        // ),
-        // (
-        //     "
-        //       import type { Type } from 'foo';
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            None,
+        ),
+        (
+            "
+              import type { Type } from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     None,
-        // ),
-        // (
-        //     "
-        //       import type * as Type from 'foo';
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            None,
+        ),
+        (
+            "
+              import type * as Type from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     None,
-        // ),
-        // (
-        //     "
-        //       import Type from 'foo';
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            None,
+        ),
+        (
+            "
+              import Type from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
-        // ),
-        // (
-        //     "
-        //       import { Type } from 'foo';
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
+        ),
+        (
+            "
+              import { Type } from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
-        // ),
-        // (
-        //     "
-        //       import * as Type from 'foo';
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
+        ),
+        (
+            "
+              import * as Type from 'foo';
 
-        //       type T = typeof Type;
-        //       type T = typeof Type.foo;
-        //     ",
-        //     Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
-        // ),
+              type T = typeof Type;
+              type U = typeof Type.foo;
+            ",
+            Some(serde_json::json!([{ "prefer": "no-type-imports" }])),
+        ),
         (
             "
               import * as Type from 'foo' assert { type: 'json' };
@@ -1,7 +1,8 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, ast::TSInterfaceDeclaration};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
@@ -9,6 +10,7 @@ use serde_json::Value;
 use crate::{
     AstNode,
     context::{ContextHost, LintContext},
+    fixer::RuleFixer,
     rule::{DefaultRuleConfig, Rule},
 };
 
@@ -66,7 +68,7 @@ declare_oxc_lint!(
     NoEmptyInterface,
     typescript,
     style,
-    pending,
+    conditional_fix_suggestion,
     config = NoEmptyInterface,
     version = "0.0.6",
     short_description = "Disallow the declaration of empty interfaces.",
@@ -84,7 +86,27 @@ impl Rule for NoEmptyInterface {
             if interface.extends.is_empty() {
                 ctx.diagnostic(no_empty_interface_diagnostic(interface.span));
             } else if interface.extends.len() == 1 && !self.allow_single_extends {
-                ctx.diagnostic(no_empty_interface_extend_diagnostic(interface.span));
+                let diagnostic = no_empty_interface_extend_diagnostic(interface.span);
+                let symbol_id = interface.id.symbol_id();
+                // Replacing the interface with a type alias would break declaration merging
+                // with a class or another interface of the same name.
+                if ctx.scoping().symbol_flags(symbol_id).is_class()
+                    || !ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+                {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+
+                let fix = |fixer: RuleFixer<'_, 'a>| {
+                    fixer
+                        .replace(interface.span, Self::type_alias_text(interface, ctx))
+                        .with_message("Replace the empty interface with a type alias")
+                };
+                if Self::is_in_ambient_declaration(symbol_id, ctx) {
+                    ctx.diagnostic_with_suggestion(diagnostic, fix);
+                } else {
+                    ctx.diagnostic_with_fix(diagnostic, fix);
+                }
             }
         }
     }
@@ -94,9 +116,40 @@ impl Rule for NoEmptyInterface {
     }
 }
 
+impl NoEmptyInterface {
+    /// `interface Foo<T> extends Bar<T> {}` -> `type Foo<T> = Bar<T>;`
+    fn type_alias_text<'a>(
+        interface: &TSInterfaceDeclaration<'a>,
+        ctx: &LintContext<'a>,
+    ) -> String {
+        let declare = if interface.declare { "declare " } else { "" };
+        let id = ctx.source_range(interface.id.span);
+        let type_parameters =
+            interface.type_parameters.as_ref().map_or("", |params| ctx.source_range(params.span));
+        let extends = ctx.source_range(interface.extends[0].span());
+        format!("{declare}type {id}{type_parameters} = {extends};")
+    }
+
+    /// Whether the interface is declared directly inside a `declare module` / `declare namespace`
+    /// block of a declaration file. Changing the declaration kind there may alter how it merges
+    /// with other declarations, so only a suggestion is offered.
+    fn is_in_ambient_declaration(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+        if !ctx.source_type().is_typescript_definition() {
+            return false;
+        }
+        let scoping = ctx.scoping();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        scoping.scope_flags(scope_id).is_ts_module_block()
+            && matches!(
+                ctx.nodes().kind(scoping.get_node_id(scope_id)),
+                AstKind::TSModuleDeclaration(module) if module.declare
+            )
+    }
+}
+
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{FixKind, tester::Tester};
 
     let pass = vec![
         (
@@ -220,6 +273,7 @@ fn test() {
             Some(serde_json::json!([{ "allowSingleExtends": false }])),
         ),
         ("interface Foo extends Array<number> {}", None),
+        ("interface Foo extends Bar {} interface Foo { baz: string }", None),
         ("interface Foo extends Array<number | {}> {}", None),
         (
             "
@@ -254,5 +308,70 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoEmptyInterface::NAME, NoEmptyInterface::PLUGIN, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("interface Foo {} interface Bar extends Foo {}", "interface Foo {} type Bar = Foo;", None),
+        ("interface Foo extends Array<number> {}", "type Foo = Array<number>;", None),
+        ("interface Foo<T> extends Bar<T> {}", "type Foo<T> = Bar<T>;", None),
+        ("export interface Foo extends Bar {}", "export type Foo = Bar;", None),
+        ("declare interface Foo extends Bar {}", "declare type Foo = Bar;", None),
+        (
+            "interface Foo extends Bar {} const bar = class Foo {};",
+            "type Foo = Bar; const bar = class Foo {};",
+            None,
+        ),
+        (
+            "interface Foo extends Bar {} interface Foo { baz: string }",
+            "interface Foo extends Bar {} interface Foo { baz: string }",
+            None,
+        ),
+        (
+            "
+            declare module FooBar {
+              type Baz = typeof baz;
+              export interface Bar extends Baz {}
+            }
+            ",
+            "
+            declare module FooBar {
+              type Baz = typeof baz;
+              export type Bar = Baz;
+            }
+            ",
+            None,
+        ),
+    ];
+
+    Tester::new(NoEmptyInterface::NAME, NoEmptyInterface::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+
+    // An empty interface in an ambient module of a declaration file only gets a suggestion.
+    let pass = vec![];
+    let fail = vec![
+        "
+        declare module FooBar {
+          type Baz = typeof baz;
+          export interface Bar extends Baz {}
+        }
+        ",
+    ];
+    let fix = vec![
+        (
+            "declare module FooBar { export interface Bar extends Baz {} }",
+            "declare module FooBar { export interface Bar extends Baz {} }",
+            None,
+            FixKind::SafeFix,
+        ),
+        (
+            "declare module FooBar { export interface Bar extends Baz {} }",
+            "declare module FooBar { export type Bar = Baz; }",
+            None,
+            FixKind::Suggestion,
+        ),
+    ];
+    Tester::new(NoEmptyInterface::NAME, NoEmptyInterface::PLUGIN, pass, fail)
+        .change_rule_path_extension("d.ts")
+        .with_snapshot_suffix("dts")
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -2,7 +2,7 @@ use oxc_allocator::ArenaVec;
 use oxc_ast::{
     AstKind,
     ast::{
-        ChainElement, Expression, FormalParameter, TSLiteral, TSType, TSTypeAnnotation, TSTypeName,
+        ChainElement, Expression, FormalParameter, TSType, TSTypeAnnotation, TSTypeName,
         UnaryOperator,
     },
 };
@@ -195,13 +195,6 @@ fn get_delete_span(
 
 fn is_inferrable_type(type_annotation: &TSTypeAnnotation, init: &Expression) -> bool {
     match &type_annotation.type_annotation {
-        TSType::TSLiteralType(ts_literal_type) => match &ts_literal_type.literal {
-            TSLiteral::BooleanLiteral(_) => is_init_boolean(init),
-            TSLiteral::NumericLiteral(_) => is_init_number(init),
-            TSLiteral::BigIntLiteral(_) => is_init_bigint(init),
-            TSLiteral::StringLiteral(_) => is_init_string(init),
-            TSLiteral::TemplateLiteral(_) | TSLiteral::UnaryExpression(_) => false,
-        },
         TSType::TSStringKeyword(_) => is_init_string(init),
         TSType::TSBigIntKeyword(_) => is_init_bigint(init),
         TSType::TSBooleanKeyword(_) => is_init_boolean(init),
@@ -430,6 +423,13 @@ fn test() {
             None,
         ),
         ("const a: any = 5;", None),
+        // Literal types are narrower than what would be inferred for `let` and parameters.
+        ("let a: 5 = 5;", None),
+        ("let a: 'foo' = 'foo';", None),
+        ("let a: true = true;", None),
+        ("let a: 10n = 10n;", None),
+        ("function fn(a: 5 = 5) {}", None),
+        ("class Foo { a: 'foo' = 'foo'; }", None),
         ("const fn = function (a: any = 5, b: any = true, c: any = 'foo') {};", None),
         (
             "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
@@ -43,6 +43,7 @@ declare_oxc_lint!(
     /// ```ts
     /// let bar: 2 = 2;
     /// let foo = { bar: 'baz' as 'baz' };
+    /// let baz = <'baz'>'baz';
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -55,7 +56,7 @@ declare_oxc_lint!(
     /// ```
     PreferAsConst,
     typescript,
-    correctness,
+    style,
     conditional_fix,
     version = "0.0.8",
     short_description = "Enforce the use of `as const` over literal types.",
@@ -101,6 +102,13 @@ impl Rule for PreferAsConst {
                     ctx,
                 );
             }
+            AstKind::TSTypeAssertion(type_assertion) => {
+                check_and_report_as_expression(
+                    &type_assertion.type_annotation,
+                    &type_assertion.expression,
+                    ctx,
+                );
+            }
             _ => {}
         }
     }
@@ -179,8 +187,9 @@ fn test() {
         "let foo = { bar: 1 as const };",
         "let foo = { bar: 'baz' };",
         "let foo = { bar: 2 };",
-        // "let foo = <bar>'bar';",
-        // "let foo = <string>'bar';",
+        "let foo = <bar>'bar';",
+        "let foo = <string>'bar';",
+        "let foo = <const>'bar';",
         "let foo = 'bar' as string;",
         "let foo = `bar` as `bar`;",
         "let foo = `bar` as `foo`;",
@@ -209,11 +218,11 @@ fn test() {
                     bar;
                   }
                 ",
-        // "
-        //           class foo {
-        //             bar = <baz>'baz';
-        //           }
-        //         ",
+        "
+                  class foo {
+                    bar = <baz>'baz';
+                  }
+                ",
         "
                   class foo {
                     bar: string = 'baz';
@@ -318,8 +327,8 @@ fn test() {
         ("const example: 'hello' = 'hello';", "const example = 'hello' as const;"),
         (r#"let foo: 'bar' = "bar";"#, r#"let foo = "bar" as const;"#),
         ("const foo: 2 = 2;", "const foo = 2 as const;"),
-        // ("let foo = <'bar'>'bar';", "let foo = <const>'bar';"),
-        // ("let foo = <4>4;", "let foo = <const>4;"),
+        ("let foo = <'bar'>'bar';", "let foo = <const>'bar';"),
+        ("let foo = <4>4;", "let foo = <const>4;"),
         ("let foo = 'bar' as 'bar';", "let foo = 'bar' as const;"),
         ("let foo = 5 as 5;", "let foo = 5 as const;"),
         (
@@ -346,18 +355,18 @@ fn test() {
             }
                   ",
         ),
-        // (
-        //     "
-        //     class foo {
-        //       foo = <'bar'>'bar';
-        //     }
-        //           ",
-        //     "
-        //     class foo {
-        //       foo = <const>'bar';
-        //     }
-        //           ",
-        // ),
+        (
+            "
+            class foo {
+              foo = <'bar'>'bar';
+            }
+                  ",
+            "
+            class foo {
+              foo = <const>'bar';
+            }
+                  ",
+        ),
         (
             "
             class foo {
@@ -385,6 +394,7 @@ fn test() {
     ];
 
     Tester::new(PreferAsConst::NAME, PreferAsConst::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
         .expect_fix(fix)
         .test_and_snapshot();
 }
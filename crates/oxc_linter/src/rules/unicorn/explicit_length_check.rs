@@ -90,7 +90,7 @@ declare_oxc_lint!(
     /// ```
     ExplicitLengthCheck,
     unicorn,
-    pedantic,
+    style,
     conditional_fix,
     config = ExplicitLengthCheck,
     version = "0.0.19",
@@ -236,9 +236,15 @@ impl ExplicitLengthCheck {
             }
             _ => node.span(),
         };
+        // The replacement is a comparison, which binds looser than the operators around it,
+        // e.g. `a instanceof !foo.length` must become `a instanceof (foo.length === 0)`.
         let parent = ctx.nodes().parent_kind(node.id());
-        let need_paren = matches!(kind, AstKind::UnaryExpression(_))
-            && matches!(parent, AstKind::UnaryExpression(_) | AstKind::AwaitExpression(_));
+        let need_paren = matches!(
+            parent,
+            AstKind::UnaryExpression(_)
+                | AstKind::AwaitExpression(_)
+                | AstKind::BinaryExpression(_)
+        );
 
         // Pre-compute source text to avoid repeated calls
         let source_text = static_member_expr.span.source_text(ctx.source_text());
@@ -416,7 +422,8 @@ fn test() {
         ("for (let i = 0; (bar && !foo.length); i ++) {}", "for (let i = 0; (bar && foo.length === 0); i ++) {}", None),
         ("const isEmpty = foo.length < 1;", "const isEmpty = foo.length === 0;", None),
         ("bar(foo.length >= 1)", "bar(foo.length > 0)", None),
-        // ("const bar = void !foo.length;", "const bar = void (foo.length === 0);", None),
+        ("const bar = void !foo.length;", "const bar = void (foo.length === 0);", None),
+        ("const bar = a in Boolean(foo.length);", "const bar = a in (foo.length > 0);", None),
         ("const isNotEmpty = Boolean(foo.length)", "const isNotEmpty = foo.length > 0", None),
         ("const isNotEmpty = Boolean(foo.length || bar)", "const isNotEmpty = Boolean(foo.length > 0 || bar)", None),
         ("const isEmpty = Boolean(!foo.length)", "const isEmpty = foo.length === 0", None),
@@ -434,8 +441,8 @@ fn test() {
         ("delete!foo.length", "delete(foo.length === 0)", None),
         ("typeof!foo.length", "typeof(foo.length === 0)", None),
         ("void!foo.length", "void(foo.length === 0)", None),
-        ("a instanceof!foo.length", "a instanceof foo.length === 0", None),
-        ("a in!foo.length", "a in foo.length === 0", None),
+        ("a instanceof!foo.length", "a instanceof(foo.length === 0)", None),
+        ("a in!foo.length", "a in(foo.length === 0)", None),
         ("export default!foo.length", "export default foo.length === 0", None),
         ("if(true){}else!foo.length", "if(true){}else foo.length === 0", None),
         ("do!foo.length;while(true) {}", "do foo.length === 0;while(true) {}", None),
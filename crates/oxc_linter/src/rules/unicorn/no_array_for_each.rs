@@ -1,10 +1,16 @@
 use oxc_ast::{
     AstKind,
-    ast::{CallExpression, Expression, FormalParameters, match_member_expression},
+    ast::{
+        ArrowFunctionExpression, CallExpression, Expression, FormalParameters, Function,
+        IdentifierReference, ReturnStatement, Statement, ThisExpression, VariableDeclaration,
+        match_member_expression,
+    },
 };
+use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{Reference, ScopeFlags};
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     AstNode,
@@ -66,10 +72,17 @@ declare_oxc_lint!(
     ///
     /// for (const [index, element] of foo.entries()) { /* ... */ }
     /// ```
+    ///
+    /// ### Fixes
+    ///
+    /// Calls used as statements whose callback takes a single parameter are rewritten to a
+    /// `for…of` loop. The fix is skipped when the callback returns, uses `this` or `arguments`
+    /// of a `function` callback, or is `async` or a generator. This fix is dangerous because
+    /// `forEach` on a non-array, such as a `Map`, iterates differently than `for…of`.
     NoArrayForEach,
     unicorn,
-    restriction,
-    pending,
+    style,
+    conditional_dangerous_fix,
     version = "0.0.19",
     short_description = "Forbids the use of `Array#forEach` in favor of a for loop.",
 );
@@ -117,7 +130,15 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span, callback_arguments(call_expr)));
+            let diagnostic = no_array_for_each_diagnostic(span, callback_arguments(call_expr));
+            if let Some((fix_span, replacement)) = for_of_replacement(node, call_expr, object, ctx)
+            {
+                ctx.diagnostic_with_dangerous_fix(diagnostic, |fixer| {
+                    fixer.replace(fix_span, replacement)
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
         }
     }
 }
@@ -158,6 +179,136 @@ fn formal_parameters_callback_arguments(params: &FormalParameters) -> CallbackAr
     }
 }
 
+/// Builds a `for…of` loop equivalent to `object.forEach(callback);`, returning the span of the
+/// enclosing expression statement and its replacement.
+fn for_of_replacement<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    object: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Span, String)> {
+    let AstKind::ExpressionStatement(stmt) = ctx.nodes().parent_kind(node.id()) else {
+        return None;
+    };
+    if call_expr.arguments.len() != 1 {
+        return None;
+    }
+
+    let (params, body, is_arrow, is_expression_body) =
+        match call_expr.arguments[0].as_expression()? {
+            Expression::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                (&arrow.params, &arrow.body, true, arrow.expression)
+            }
+            Expression::FunctionExpression(func)
+                if !func.r#async && !func.generator && func.id.is_none() =>
+            {
+                (&func.params, func.body.as_ref()?, false, false)
+            }
+            _ => return None,
+        };
+
+    if params.rest.is_some() || params.items.len() != 1 || !body.directives.is_empty() {
+        return None;
+    }
+    let param = &params.items[0];
+    if param.initializer.is_some() {
+        return None;
+    }
+
+    let mut finder = UnfixableCallbackFinder { is_arrow, arrow_depth: 0, found: false };
+    finder.visit_function_body(body);
+    if finder.found {
+        return None;
+    }
+
+    let bindings = param.pattern.get_binding_identifiers();
+    // `for (const element of element.children)` would read `element` in its TDZ.
+    let mut names = ReferencedNames { names: vec![] };
+    names.visit_expression(object);
+    if bindings.iter().any(|binding| names.names.contains(&binding.name.as_str())) {
+        return None;
+    }
+    let is_reassigned = bindings.iter().any(|binding| {
+        ctx.semantic().symbol_references(binding.symbol_id()).any(Reference::is_write)
+    });
+
+    let body_text = match body.statements.as_slice() {
+        [Statement::ExpressionStatement(expr_stmt)] if is_expression_body => {
+            let semicolon = if ctx.source_range(stmt.span).ends_with(';') { ";" } else { "" };
+            format!("{}{semicolon}", ctx.source_range(expr_stmt.expression.span()))
+        }
+        _ => ctx.source_range(body.span).to_string(),
+    };
+
+    Some((
+        stmt.span,
+        format!(
+            "for ({} {} of {}) {body_text}",
+            if is_reassigned { "let" } else { "const" },
+            ctx.source_range(param.pattern.span()),
+            ctx.source_range(object.span()),
+        ),
+    ))
+}
+
+/// Looks for anything in a callback body that changes meaning once the body is moved into a loop.
+struct UnfixableCallbackFinder {
+    is_arrow: bool,
+    arrow_depth: u32,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for UnfixableCallbackFinder {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        if self.arrow_depth == 0 {
+            self.found = true;
+        }
+        oxc_ast_visit::walk::walk_return_statement(self, it);
+    }
+
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        if it.kind.is_var() && self.arrow_depth == 0 {
+            self.found = true;
+        }
+        oxc_ast_visit::walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        if !self.is_arrow {
+            self.found = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if !self.is_arrow && it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, _flags: ScopeFlags) {
+        // Function declarations are hoisted differently once they sit in a loop body.
+        if it.is_declaration() && self.arrow_depth == 0 {
+            self.found = true;
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        oxc_ast_visit::walk::walk_arrow_function_expression(self, it);
+        self.arrow_depth -= 1;
+    }
+}
+
+struct ReferencedNames<'a> {
+    names: Vec<&'a str>,
+}
+
+impl<'a> Visit<'a> for ReferencedNames<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.names.push(it.name.as_str());
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -190,9 +341,7 @@ fn test() {
         "return foo.forEach(element => {bar(element)});",
     ];
 
-    // TODO: Implement a fixer.
-    #[expect(clippy::no_effect_underscore_binding)]
-    let _fix = [
+    let fix = vec![
         (
             "foo.forEach(function(element) {
                 delete element;
@@ -225,15 +374,34 @@ fn test() {
             "while (true) return;
             for (const element of foo) bar(element);",
         ),
+        ("foo.forEach(({ a, b }) => { a = b; });", "for (let { a, b } of foo) { a = b; }"),
+        ("foo.forEach((x: number) => bar(x));", "for (const x of foo) bar(x);"),
         (
-            "foo.forEach(_ => {
-                with (a) return {};
-            })",
-            "for (const _ of foo) {
-                with (a)  { ({}); continue; }
-            }",
+            "foo.forEach(x => { items.forEach(y => { return y; }); });",
+            "for (const x of foo) { items.forEach(y => { return y; }); }",
+        ),
+        // Unfixable callbacks.
+        ("foo.forEach(_ => { with (a) return {}; })", "foo.forEach(_ => { with (a) return {}; })"),
+        ("foo.forEach((a, b) => bar(a, b));", "foo.forEach((a, b) => bar(a, b));"),
+        ("foo.forEach(bar);", "foo.forEach(bar);"),
+        ("foo.forEach(x => bar(x), thisArg);", "foo.forEach(x => bar(x), thisArg);"),
+        ("foo.forEach(async x => await bar(x));", "foo.forEach(async x => await bar(x));"),
+        (
+            "foo.forEach(function(x) { this.bar(x); });",
+            "foo.forEach(function(x) { this.bar(x); });",
+        ),
+        (
+            "foo.forEach(function(x) { bar(arguments); });",
+            "foo.forEach(function(x) { bar(arguments); });",
         ),
+        ("foo.forEach(x => { var y = x; });", "foo.forEach(x => { var y = x; });"),
+        ("foo.forEach((x = 1) => bar(x));", "foo.forEach((x = 1) => bar(x));"),
+        ("x.children.forEach(x => bar(x));", "x.children.forEach(x => bar(x));"),
+        ("const a = foo.forEach(x => bar(x));", "const a = foo.forEach(x => bar(x));"),
+        ("foo?.forEach(x => bar(x));", "foo?.forEach(x => bar(x));"),
     ];
 
-    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
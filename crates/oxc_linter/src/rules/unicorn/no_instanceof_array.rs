@@ -56,7 +56,10 @@ impl Rule for NoInstanceofArray {
         }
 
         match &expr.right.without_parentheses() {
-            Expression::Identifier(identifier) if identifier.name == "Array" => {
+            Expression::Identifier(identifier)
+                if identifier.name == "Array"
+                    && ctx.is_reference_to_global_variable(identifier) =>
+            {
                 ctx.diagnostic_with_fix(no_instanceof_array_diagnostic(expr.span), |fixer| {
                     let argument = fixer.source_range(expr.left.span());
                     let mut code = String::with_capacity(15 + argument.len());
@@ -84,6 +87,9 @@ fn test() {
         ("a.x[2] instanceof foo()", None),
         ("Array.isArray([1,2,3]) === true", None),
         ("\"arr instanceof Array\"", None),
+        ("const Array = MyArray; arr instanceof Array", None),
+        ("function foo(Array) { return arr instanceof Array }", None),
+        ("import { Array } from 'foo'; arr instanceof Array", None),
     ];
 
     let fail = vec![
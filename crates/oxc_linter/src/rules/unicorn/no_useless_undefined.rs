@@ -140,13 +140,10 @@ fn is_function_bind_call(call_expr: &CallExpression) -> bool {
     !call_expr.optional && is_method_call(call_expr, None, Some(&["bind"]), None, None)
 }
 
-fn is_undefined(arg: &Argument) -> bool {
-    if !arg.is_expression() {
-        return false;
-    }
-    let expr: &Expression = arg.to_expression();
-    if let Expression::Identifier(_) = expr {
-        return expr.is_undefined();
+/// Whether `arg` is the global `undefined`, and not a local binding that shadows it.
+fn is_undefined(arg: &Argument, ctx: &LintContext) -> bool {
+    if let Argument::Identifier(ident) = arg {
+        return ident.name == "undefined" && ctx.is_reference_to_global_variable(ident);
     }
     false
 }
@@ -171,7 +168,8 @@ impl Rule for NoUselessUndefined {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IdentifierReference(undefined_literal)
-                if undefined_literal.name == "undefined" =>
+                if undefined_literal.name == "undefined"
+                    && ctx.is_reference_to_global_variable(undefined_literal) =>
             {
                 let Some(parent_node) = outermost_paren_parent(node, ctx) else {
                     return;
@@ -313,7 +311,7 @@ impl Rule for NoUselessUndefined {
                 let mut undefined_args_spans = Vec::new();
                 for i in (0..arguments.len()).rev() {
                     let arg = &arguments[i];
-                    if is_undefined(arg) {
+                    if is_undefined(arg, ctx) {
                         let span = arg.span();
                         undefined_args_spans.insert(0, span);
                     } else {
@@ -371,6 +369,10 @@ fn test() {
         ("function foo(bar) {}", None),
         // I guess nobody uses this, but `yield* undefined;` is valid code, and `yield*;` is not
         (r"function* foo() {yield* undefined;}", None),
+        // `undefined` is shadowed by a local binding
+        ("function foo(undefined) { return undefined; }", None),
+        ("function foo(undefined) { bar(undefined); }", None),
+        ("function foo(undefined) { let a = undefined; }", None),
         // Ignored
         ("if (Object.is(foo, undefined)){}", None),
         ("t.is(foo, undefined)", None),
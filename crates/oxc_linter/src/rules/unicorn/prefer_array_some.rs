@@ -52,7 +52,7 @@ declare_oxc_lint!(
     /// foo.findIndex(bar) < 0;
     /// foo.findIndex(element => element.bar === 1) !== -1;
     /// foo.findLastIndex(element => element.bar === 1) !== -1;
-    /// array.filter(fn).length === 0;
+    /// array.filter(fn).length > 0;
     /// ```
     ///
     /// Examples of **correct** code for this rule:
@@ -63,7 +63,7 @@ declare_oxc_lint!(
     /// ```
     PreferArraySome,
     unicorn,
-    pedantic,
+    style,
     suggestion,
     version = "0.0.18",
     short_description = "Prefer using `Array#some()` over various alternatives.",
@@ -219,7 +219,9 @@ impl Rule for PreferArraySome {
                     return;
                 }
 
-                let Expression::NumericLiteral(right_num_lit) = &bin_expr.right else {
+                let Expression::NumericLiteral(right_num_lit) =
+                    bin_expr.right.without_parentheses()
+                else {
                     return;
                 };
 
@@ -278,13 +280,18 @@ impl Rule for PreferArraySome {
                             return fixer.noop();
                         };
 
-                        // Replace `filter` with `some` and delete `.length > 0` or `.length !== 0`
+                        // Replace `filter` with `some`, then delete `.length` and `> 0` or `!== 0`
+                        // separately so that parentheses around either side are kept balanced.
                         let multi_fixer = fixer.for_multifix();
-                        let mut multi_fix = multi_fixer.new_fix_with_capacity(2);
+                        let mut multi_fix = multi_fixer.new_fix_with_capacity(3);
                         multi_fix.push(multi_fixer.replace(target_span, "some"));
+                        multi_fix.push(multi_fixer.delete_range(Span::new(
+                            left_member_expr.object().span().end,
+                            left_member_expr.span().end,
+                        )));
                         multi_fix.push(
                             multi_fixer.delete_range(Span::new(
-                                left_call_expr.span.end,
+                                bin_expr.left.span().end,
                                 bin_expr.span.end,
                             )),
                         );
@@ -509,24 +516,23 @@ fn test() {
         // .filter(…).length
         "array.filter(fn).length > 0",
         "array.filter(fn).length !== 0",
-        // TODO: Get this working.
-        // "
-        // if (
-        //     ((
-        //         ((
-        //             ((
-        //                 ((
-        //                     array
-        //                 ))
-        //                     .filter(what_ever_here)
-        //             ))
-        //                 .length
-        //         ))
-        //         >
-        //         (( 0 ))
-        //     ))
-        // );
-        // ",
+        "
+        if (
+            ((
+                ((
+                    ((
+                        ((
+                            array
+                        ))
+                            .filter(what_ever_here)
+                    ))
+                        .length
+                ))
+                >
+                (( 0 ))
+            ))
+        );
+        ",
         // Compare with `undefined`
         "foo.find(fn) == null",
         "foo.find(fn) == undefined",
@@ -600,6 +606,11 @@ fn test() {
         // .filter(…).length
         ("array.filter(fn).length > 0", "array.some(fn)"),
         ("array.filter(fn).length !== 0", "array.some(fn)"),
+        ("if (((array.filter(fn)).length) > (( 0 )));", "if (((array.some(fn))));"),
+        (
+            "const foo = array.filter(fn).length > 0 ? bar : baz;",
+            "const foo = array.some(fn) ? bar : baz;",
+        ),
         // Compare with `undefined`
         ("foo.find(fn) == null", "!foo.some(fn)"),
         ("foo.find(fn) == undefined", "!foo.some(fn)"),
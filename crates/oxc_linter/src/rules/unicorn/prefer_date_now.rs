@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, context::LintContext, rule::Rule, utils::pad_fix_with_token_boundary};
 
 fn prefer_date_now(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer `Date.now()` over `new Date()`")
@@ -53,7 +53,7 @@ declare_oxc_lint!(
     /// ```
     PreferDateNow,
     unicorn,
-    pedantic,
+    style,
     fix,
     version = "0.0.16",
     short_description = "Prefers use of `Date.now()` over `new Date().getTime()` or `new Date().valueOf()`.",
@@ -114,7 +114,18 @@ impl Rule for PreferDateNow {
                 }
                 if is_new_date(&unary_expr.argument) {
                     ctx.diagnostic_with_fix(prefer_date_now(unary_expr.argument.span()), |fixer| {
-                        fixer.replace(unary_expr.span, "Date.now()")
+                        // `-new Date()` must keep its sign, so only the operand is replaced.
+                        if unary_expr.operator == UnaryOperator::UnaryNegation {
+                            return fixer.replace(unary_expr.argument.span(), "Date.now()");
+                        }
+                        // `+` is dropped, so avoid gluing onto a preceding keyword, e.g. `return+new Date`.
+                        let mut replacement = String::from("Date.now()");
+                        pad_fix_with_token_boundary(
+                            ctx.source_text(),
+                            unary_expr.span,
+                            &mut replacement,
+                        );
+                        fixer.replace(unary_expr.span, replacement)
                     });
                 }
             }
@@ -245,6 +256,12 @@ fn test() {
         ("Number(new Date()        )", "Date.now()"),
         ("BigInt(new             Date());", "BigInt(Date.now());"),
         ("BigInt(new Date());", "BigInt(Date.now());"),
+        ("const ts = + /* 1 */ new Date;", "const ts = Date.now();"),
+        ("const ts = - /* 1 */ new Date();", "const ts = - /* 1 */ Date.now();"),
+        ("const ts = a + +new Date();", "const ts = a + Date.now();"),
+        ("const ts = a - -new Date();", "const ts = a - -Date.now();"),
+        ("function foo(){return+new Date}", "function foo(){return Date.now()}"),
+        ("function foo(){return-new Date}", "function foo(){return-Date.now()}"),
     ];
 
     Tester::new(PreferDateNow::NAME, PreferDateNow::PLUGIN, pass, fail)
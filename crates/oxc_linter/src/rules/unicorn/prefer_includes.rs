@@ -55,6 +55,12 @@ declare_oxc_lint!(
     /// ```javascript
     /// if (str.includes('foo')) { }
     /// ```
+    ///
+    /// ### Fixes
+    ///
+    /// A suggestion is only offered when `indexOf()` is called with a single argument.
+    /// Calls passing a `fromIndex` argument (e.g. `str.indexOf('foo', 1) !== -1`) are still
+    /// reported, but must be rewritten by hand.
     PreferIncludes,
     unicorn,
     style,
@@ -148,14 +154,14 @@ impl Rule for PreferIncludes {
         let call_operator = if left_call_expr.optional { "?.(" } else { "(" };
         let has_optional_chain = left_call_expr.optional || member_expr.optional();
 
-        // Get arguments text
-        let args_text = left_call_expr
-            .arguments
-            .iter()
-            .map(|arg| ctx.source_range(arg.span()))
-            .collect::<Vec<_>>()
-            .join(", ");
+        // `fromIndex` changes where the search starts, so leave rewriting those calls to the user.
+        if left_call_expr.arguments.len() > 1 {
+            ctx.diagnostic(prefer_includes_diagnostic(callee_span));
+            return;
+        }
 
+        let args_text =
+            left_call_expr.arguments.first().map_or("", |arg| ctx.source_range(arg.span()));
         let fix_span = bin_expr.span;
         ctx.diagnostic_with_suggestion(prefer_includes_diagnostic(callee_span), |fixer| {
             let includes_call =
@@ -224,6 +230,7 @@ fn test() {
         r"(a || b).indexOf('foo') === -1",
         r"foo.indexOf(bar, 0) !== -1",
         r"foo.indexOf(bar, 1) !== -1",
+        r"foo?.indexOf(bar, i) < 0",
         r"foo?.indexOf('x') !== -1",
         r"foo.indexOf?.('x') === -1",
         r"foo?.indexOf?.('x') == -1",
@@ -241,8 +248,9 @@ fn test() {
         (r"[1,2,3].indexOf(4) !== -1", r"[1,2,3].includes(4)"),
         (r"str.indexOf('foo') < 0", r"!str.includes('foo')"),
         (r"''.indexOf('foo') < 0", r"!''.includes('foo')"),
-        (r"foo.indexOf(bar, 0) !== -1", r"foo.includes(bar, 0)"),
-        (r"foo.indexOf(bar, 1) !== -1", r"foo.includes(bar, 1)"),
+        (r"foo.indexOf(bar, 0) !== -1", r"foo.indexOf(bar, 0) !== -1"),
+        (r"foo.indexOf(bar, 1) !== -1", r"foo.indexOf(bar, 1) !== -1"),
+        (r"foo?.indexOf(bar, i) < 0", r"foo?.indexOf(bar, i) < 0"),
         (r"foo?.indexOf('x') !== -1", r"foo?.includes('x') !== false"),
         (r"foo.indexOf?.('x') === -1", r"foo.includes?.('x') === false"),
         (r"foo?.indexOf?.('x') == -1", r"foo?.includes?.('x') === false"),
@@ -48,6 +48,21 @@ fn is_value_not_usable(node: &AstNode, ctx: &LintContext) -> bool {
     )
 }
 
+/// Whether `expr` can be used as the object of a member expression without wrapping it in parentheses.
+fn is_safe_member_object(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::Identifier(_)
+            | Expression::ThisExpression(_)
+            | Expression::ArrayExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ParenthesizedExpression(_)
+            | Expression::StaticMemberExpression(_)
+            | Expression::ComputedMemberExpression(_)
+            | Expression::PrivateFieldExpression(_)
+    )
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -117,9 +132,16 @@ impl Rule for PreferModernDomApis {
             if is_value_not_usable(node, ctx) {
                 ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
                     let new_node = ctx.source_range(call_expr.arguments[0].span());
-                    let old_node = ctx.source_range(call_expr.arguments[1].span());
+                    let old_node_expr = call_expr.arguments[1].to_expression();
+                    let old_node = ctx.source_range(old_node_expr.span());
 
-                    let replacement = format!("{old_node}.{preferred_method}({new_node})");
+                    // The second argument becomes the object of the call, e.g.
+                    // `parentNode.replaceChild(newNode, a || b)` -> `(a || b).replaceWith(newNode)`
+                    let replacement = if is_safe_member_object(old_node_expr) {
+                        format!("{old_node}.{preferred_method}({new_node})")
+                    } else {
+                        format!("({old_node}).{preferred_method}({new_node})")
+                    };
 
                     fixer.replace(call_expr.span, replacement)
                 });
@@ -225,6 +247,7 @@ fn test() {
         "const foo = parentNode.replaceChild(newChildNode, oldChildNode);",
         "foo = parentNode.replaceChild(newChildNode, oldChildNode);",
         "parentNode.insertBefore(newNode, referenceNode);",
+        "parentNode.replaceChild(newChildNode, a || b);",
         "parentNode.insertBefore(alfa, beta).insertBefore(charlie, delta);",
         "const foo = parentNode.insertBefore(alfa, beta);",
         "foo = parentNode.insertBefore(alfa, beta);",
@@ -263,6 +286,18 @@ fn test() {
             "oldChildNode.replaceWith(newChildNode);",
         ),
         ("parentNode.insertBefore(newNode, referenceNode);", "referenceNode.before(newNode);"),
+        (
+            "parentNode.replaceChild(newChildNode, this.nodes[0]);",
+            "this.nodes[0].replaceWith(newChildNode);",
+        ),
+        (
+            "parentNode.replaceChild(createNode(), getOldNode());",
+            "getOldNode().replaceWith(createNode());",
+        ),
+        ("parentNode.replaceChild(newChildNode, a || b);", "(a || b).replaceWith(newChildNode);"),
+        ("parentNode.insertBefore(newNode, cond ? a : b);", "(cond ? a : b).before(newNode);"),
+        ("parentNode.insertBefore(newNode, foo?.bar);", "(foo?.bar).before(newNode);"),
+        ("parentNode.insertBefore(newNode, (node));", "(node).before(newNode);"),
         (
             r#"referenceNode.insertAdjacentText("beforebegin", "text");"#,
             r#"referenceNode.before("text");"#,
@@ -110,7 +110,7 @@ impl Rule for PreferNegativeIndex {
             _ => return,
         };
 
-        let mut member_exprs: Vec<&StaticMemberExpression> = Vec::new();
+        let mut member_exprs: Vec<(&StaticMemberExpression, &Expression)> = Vec::new();
         let range_increment = if matches!(callee_name, "slice" | "subarray") { 2 } else { 1 };
         let arg_range_start = usize::from(is_prototype);
         let arg_range_end = if is_prototype_apply {
@@ -130,12 +130,12 @@ impl Rule for PreferNegativeIndex {
 
             match arg_expr {
                 Expression::BinaryExpression(binary_expr) => {
-                    let Some(member_expr) = get_binary_left_expr(binary_expr) else {
+                    let Some((member_expr, index_expr)) = get_binary_left_expr(binary_expr) else {
                         continue;
                     };
 
                     if is_same_node(identifier_expr, &member_expr.object, ctx) {
-                        member_exprs.push(member_expr);
+                        member_exprs.push((member_expr, index_expr));
                     }
                 }
                 Expression::ArrayExpression(array_expr) => {
@@ -144,12 +144,14 @@ impl Rule for PreferNegativeIndex {
                             break;
                         }
                         if let ArrayExpressionElement::BinaryExpression(binary_expr) = element {
-                            let Some(el_member_expr) = get_binary_left_expr(binary_expr) else {
+                            let Some((el_member_expr, index_expr)) =
+                                get_binary_left_expr(binary_expr)
+                            else {
                                 continue;
                             };
 
                             if is_same_node(identifier_expr, &el_member_expr.object, ctx) {
-                                member_exprs.push(el_member_expr);
+                                member_exprs.push((el_member_expr, index_expr));
                             }
                         }
                     }
@@ -162,8 +164,19 @@ impl Rule for PreferNegativeIndex {
             ctx.diagnostic_with_fix(prefer_negative_index_diagnostic(call_expr.span), |fixer| {
                 let mut fixes = fixer.new_fix_with_capacity(member_exprs.len());
 
-                for member_expr in member_exprs {
+                for (member_expr, index_expr) in member_exprs {
                     let member_expr_span = member_expr.span();
+
+                    // `foo.length - 1` -> `-1`, unless there are comments around the operator.
+                    let operator_span = Span::new(member_expr_span.end, index_expr.span().start);
+                    if ctx.source_range(operator_span).trim() == "-" {
+                        fixes.push(Fix::new(
+                            "-",
+                            Span::new(member_expr_span.start, operator_span.end),
+                        ));
+                        continue;
+                    }
+
                     let member_expr_next_end = member_expr_span.end + 1;
                     let member_expr_with_next_span =
                         Span::new(member_expr_span.start, member_expr_next_end);
@@ -248,9 +261,11 @@ fn get_prototype_callee_type(expression: &Expression) -> TypeOptions {
     }
 }
 
+/// Returns the `.length` member expression on the left of `binary_expr`, together with the
+/// number it is subtracted by, e.g. `foo.length` and `1` for `foo.length - 1`.
 fn get_binary_left_expr<'a>(
-    binary_expr: &'a BinaryExpression,
-) -> Option<&'a StaticMemberExpression<'a>> {
+    binary_expr: &'a BinaryExpression<'a>,
+) -> Option<(&'a StaticMemberExpression<'a>, &'a Expression<'a>)> {
     if !matches!(binary_expr.operator, BinaryOperator::Subtraction)
         || !binary_expr.right.is_number_literal() | binary_expr.right.is_number_0()
     {
@@ -269,7 +284,7 @@ fn get_binary_left_expr<'a>(
         Expression::BinaryExpression(inner_binary_expr) => get_binary_left_expr(inner_binary_expr),
         Expression::StaticMemberExpression(member_expr) => {
             if member_expr.property.name == "length" {
-                return Some(member_expr.as_ref());
+                return Some((member_expr.as_ref(), &binary_expr.right));
             }
 
             None
@@ -447,22 +462,22 @@ fn test() {
     ];
 
     let fix = vec![
-        ("foo.slice(foo.length - 2, foo.length - 1)", "foo.slice(- 2, - 1)", None),
-        ("foo.splice(foo.length - 1, 1)", "foo.splice(- 1, 1)", None),
+        ("foo.slice(foo.length - 2, foo.length - 1)", "foo.slice(-2, -1)", None),
+        ("foo.splice(foo.length - 1, 1)", "foo.splice(-1, 1)", None),
         (
             "Array.prototype.slice.call(foo, foo.length - 2, foo.length - 1)",
-            "Array.prototype.slice.call(foo, - 2, - 1)",
+            "Array.prototype.slice.call(foo, -2, -1)",
             None,
         ),
         (
             "Array.prototype.slice.apply(foo, [foo.length - 2, foo.length - 1])",
-            "Array.prototype.slice.apply(foo, [- 2, - 1])",
+            "Array.prototype.slice.apply(foo, [-2, -1])",
             None,
         ),
-        ("foo.slice(foo.length - 1 - 1)", "foo.slice(- 1 - 1)", None),
-        ("foo.bar.slice(foo.bar.length - 1)", "foo.bar.slice(- 1)", None),
-        ("foo['bar'].slice(foo['bar'].length - 1)", "foo['bar'].slice(- 1)", None),
-        ("foo[1].slice(foo[1].length - 1)", "foo[1].slice(- 1)", None),
+        ("foo.slice(foo.length - 1 - 1)", "foo.slice(-1 - 1)", None),
+        ("foo.bar.slice(foo.bar.length - 1)", "foo.bar.slice(-1)", None),
+        ("foo['bar'].slice(foo['bar'].length - 1)", "foo['bar'].slice(-1)", None),
+        ("foo[1].slice(foo[1].length - 1)", "foo[1].slice(-1)", None),
         ("foo.slice(foo.length/* comment */ - 1)", "foo.slice(/* comment */ - 1)", None),
         (
             "
@@ -495,10 +510,10 @@ fn test() {
                                 ",
             None,
         ),
-        ("foo.slice((foo.length - 1) - 1)", "foo.slice((- 1) - 1)", None),
+        ("foo.slice((foo.length - 1) - 1)", "foo.slice((-1) - 1)", None),
         (
             "foo.slice(/* will keep */(/* will keep 1 */foo.length - 1) - 1)",
-            "foo.slice(/* will keep */(/* will keep 1 */- 1) - 1)",
+            "foo.slice(/* will keep */(/* will keep 1 */-1) - 1)",
             None,
         ),
         (
@@ -509,10 +524,10 @@ fn test() {
                                     [].splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                 ",
             "
-                                    [].slice.call(foo, - 1, - 2, foo.length - 3);
-                                    [].splice.call(foo, - 1, foo.length - 2, foo.length - 3);
-                                    [].slice.apply(foo, [- 1, - 2, foo.length - 3]);
-                                    [].splice.apply(foo, [- 1, foo.length - 2, foo.length - 3]);
+                                    [].slice.call(foo, -1, -2, foo.length - 3);
+                                    [].splice.call(foo, -1, foo.length - 2, foo.length - 3);
+                                    [].slice.apply(foo, [-1, -2, foo.length - 3]);
+                                    [].splice.apply(foo, [-1, foo.length - 2, foo.length - 3]);
                                 ",
             None,
         ),
@@ -522,9 +537,9 @@ fn test() {
                                     ''.slice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                     ''.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                 ", "
-                                    ''.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    ''.slice.call(foo, -1, -2, foo.length - 3);
                                     ''.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    ''.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    ''.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     ''.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                 ", None),
         ("
@@ -559,33 +574,33 @@ fn test() {
                                     NOT_SUPPORTED.prototype.slice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
                                     NOT_SUPPORTED.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
                                 ", "
-                                    Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
-                                    Array.prototype.splice.call(foo, - 1, foo.length - 2, foo.length - 3);
-                                    String.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
+                                    Array.prototype.splice.call(foo, -1, foo.length - 2, foo.length - 3);
+                                    String.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     String.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    ArrayBuffer.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    ArrayBuffer.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     ArrayBuffer.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Int8Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Int8Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Int8Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Uint8Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Uint8Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Uint8Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Uint8ClampedArray.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Uint8ClampedArray.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Uint8ClampedArray.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Int16Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Int16Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Int16Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Uint16Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Uint16Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Uint16Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Int32Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Int32Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Int32Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Uint32Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Uint32Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Uint32Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Float32Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Float32Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Float32Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    Float64Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    Float64Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     Float64Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    BigInt64Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    BigInt64Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     BigInt64Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
-                                    BigUint64Array.prototype.slice.call(foo, - 1, - 2, foo.length - 3);
+                                    BigUint64Array.prototype.slice.call(foo, -1, -2, foo.length - 3);
                                     BigUint64Array.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
                                     NOT_SUPPORTED.prototype.slice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
                                     NOT_SUPPORTED.prototype.splice.call(foo, foo.length - 1, foo.length - 2, foo.length - 3);
@@ -622,33 +637,33 @@ fn test() {
                                     NOT_SUPPORTED.prototype.slice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                     NOT_SUPPORTED.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                 ", "
-                                    Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
-                                    Array.prototype.splice.apply(foo, [- 1, foo.length - 2, foo.length - 3]);
-                                    String.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
+                                    Array.prototype.splice.apply(foo, [-1, foo.length - 2, foo.length - 3]);
+                                    String.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     String.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    ArrayBuffer.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    ArrayBuffer.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     ArrayBuffer.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Int8Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Int8Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Int8Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Uint8Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Uint8Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Uint8Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Uint8ClampedArray.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Uint8ClampedArray.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Uint8ClampedArray.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Int16Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Int16Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Int16Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Uint16Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Uint16Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Uint16Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Int32Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Int32Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Int32Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Uint32Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Uint32Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Uint32Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Float32Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Float32Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Float32Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    Float64Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    Float64Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     Float64Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    BigInt64Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    BigInt64Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     BigInt64Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
-                                    BigUint64Array.prototype.slice.apply(foo, [- 1, - 2, foo.length - 3]);
+                                    BigUint64Array.prototype.slice.apply(foo, [-1, -2, foo.length - 3]);
                                     BigUint64Array.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                     NOT_SUPPORTED.prototype.slice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
                                     NOT_SUPPORTED.prototype.splice.apply(foo, [foo.length - 1, foo.length - 2, foo.length - 3]);
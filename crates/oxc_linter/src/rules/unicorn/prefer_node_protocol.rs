@@ -1,13 +1,13 @@
 use nodejs_built_in_modules::is_nodejs_builtin_module;
 use oxc_ast::{
     AstKind,
-    ast::{Expression, TSModuleReference},
+    ast::{Argument, Expression, MemberExpression, TSModuleReference},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
 
 fn prefer_node_protocol_diagnostic(span: Span, module_name: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -34,15 +34,19 @@ declare_oxc_lint!(
     /// Examples of **incorrect** code for this rule:
     /// ```javascript
     /// import fs from "fs";
+    /// export * from "fs/promises";
+    /// const fs = process.getBuiltinModule("fs");
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// import fs from "node:fs";
+    /// export * from "node:fs/promises";
+    /// const fs = process.getBuiltinModule("node:fs");
     /// ```
     PreferNodeProtocol,
     unicorn,
-    restriction,
+    style,
     fix,
     version = "0.0.19",
     short_description = "Prefer using the `node:` protocol when importing Node.js built-in modules.",
@@ -62,12 +66,31 @@ impl Rule for PreferNodeProtocol {
                 _ => None,
             },
             AstKind::CallExpression(call) if !call.optional => {
-                call.common_js_require().map(|s| (s.value, s.span))
+                call.common_js_require().map(|s| (s.value, s.span)).or_else(|| {
+                    // `process.getBuiltinModule("fs")`
+                    if !is_method_call(
+                        call,
+                        Some(&["process"]),
+                        Some(&["getBuiltinModule"]),
+                        Some(1),
+                        Some(1),
+                    ) || call.callee.get_member_expr().is_some_and(MemberExpression::optional)
+                    {
+                        return None;
+                    }
+                    match &call.arguments[0] {
+                        Argument::StringLiteral(str_lit) => Some((str_lit.value, str_lit.span)),
+                        _ => None,
+                    }
+                })
             }
             AstKind::ImportDeclaration(import) => Some((import.source.value, import.source.span)),
             AstKind::ExportNamedDeclaration(export) => {
                 export.source.as_ref().map(|item| (item.value, item.span))
             }
+            AstKind::ExportAllDeclaration(export) => {
+                Some((export.source.value, export.source.span))
+            }
             _ => return,
         };
         let Some((string_lit_value, span)) = string_lit_value_with_span else {
@@ -125,6 +148,8 @@ fn test() {
         r#"const fs = require(...["fs"]);"#,
         r#"const fs = require("unicorn");"#,
         r#"const fs = process.getBuiltinModule("node:fs")"#,
+        r#"export * from "node:fs";"#,
+        r#"export * from "./fs";"#,
         r#"const fs = process.getBuiltinModule?.("fs")"#,
         r#"const fs = process?.getBuiltinModule("fs")"#,
         r#"const fs = process.notGetBuiltinModule("fs")"#,
@@ -171,7 +196,10 @@ fn test() {
         r#"import "timers/promises";"#,
         r#"const {promises} = require("fs")"#,
         "const fs = require('fs/promises')",
-        // r#"const fs = process.getBuiltinModule("fs")"#,
+        r#"const fs = process.getBuiltinModule("fs")"#,
+        r#"const fs = process.getBuiltinModule("fs/promises")"#,
+        r#"export * from "fs";"#,
+        r#"export * as fs from "fs/promises";"#,
         r#"export fs from "fs";"#,
         "await import('assert/strict')",
     ];
@@ -184,6 +212,11 @@ fn test() {
         ("import fs = require('fs');", "import fs = require('node:fs');"),
         (r#"import "child_process";"#, r#"import "node:child_process";"#),
         (r#"import fs from "fs/promises";"#, r#"import fs from "node:fs/promises";"#),
+        (r#"export * from "fs/promises";"#, r#"export * from "node:fs/promises";"#),
+        (
+            r#"const fs = process.getBuiltinModule("fs")"#,
+            r#"const fs = process.getBuiltinModule("node:fs")"#,
+        ),
     ];
 
     Tester::new(PreferNodeProtocol::NAME, PreferNodeProtocol::PLUGIN, pass, fail)
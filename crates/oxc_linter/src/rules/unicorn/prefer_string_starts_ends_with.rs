@@ -1,11 +1,14 @@
 use oxc_ast::{
     AstKind,
-    ast::{CallExpression, Expression, MemberExpression, RegExpFlags, RegExpLiteral},
+    ast::{
+        BinaryExpression, CallExpression, Expression, MemberExpression, RegExpFlags, RegExpLiteral,
+    },
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_regular_expression::ast::{BoundaryAssertionKind, Term};
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
 
 use crate::{
     AstNode,
@@ -18,6 +21,11 @@ fn starts_with(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer String#startsWith over a regex with a caret.").with_label(span)
 }
 
+fn starts_with_index_of(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Prefer String#startsWith over comparing String#indexOf with 0.")
+        .with_label(span)
+}
+
 fn ends_with(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer String#endsWith over a regex with a dollar sign.").with_label(span)
 }
@@ -44,25 +52,36 @@ declare_oxc_lint!(
     /// ```javascript
     /// const foo = "hello";
     /// /^abc/.test(foo);
+    /// foo.toLowerCase().indexOf("abc") === 0;
     /// ```
     ///
     /// Examples of **correct** code for this rule:
     /// ```javascript
     /// const foo = "hello";
     /// foo.startsWith("abc");
+    /// foo.toLowerCase().startsWith("abc");
     /// ```
+    ///
+    /// `indexOf` comparisons are only reported when the receiver is known to be a string, such as
+    /// a string literal or the result of `String()` or `toLowerCase()`, since `Array#indexOf`
+    /// can't be replaced with `startsWith`.
     PreferStringStartsEndsWith,
     unicorn,
-    correctness,
-    fix,
+    style,
+    fix_or_suggestion,
     version = "0.0.18",
     short_description = "Prefer `String#startsWith()` and `String#endsWith()` over `RegExp#test()`.",
 );
 
 impl Rule for PreferStringStartsEndsWith {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::CallExpression(call_expr) = node.kind() else {
-            return;
+        let call_expr = match node.kind() {
+            AstKind::CallExpression(call_expr) => call_expr,
+            AstKind::BinaryExpression(binary_expr) => {
+                check_index_of_comparison(binary_expr, ctx);
+                return;
+            }
+            _ => return,
         };
 
         if call_expr.optional {
@@ -104,6 +123,112 @@ impl Rule for PreferStringStartsEndsWith {
     }
 }
 
+/// `str.indexOf(x) === 0` => `str.startsWith(x)`
+fn check_index_of_comparison<'a>(binary_expr: &BinaryExpression<'a>, ctx: &LintContext<'a>) {
+    if !binary_expr.operator.is_equality() {
+        return;
+    }
+
+    let is_zero = |expr: &Expression| matches!(expr.without_parentheses(), Expression::NumericLiteral(lit) if lit.value == 0.0);
+    let index_of = if is_zero(&binary_expr.right) {
+        &binary_expr.left
+    } else if is_zero(&binary_expr.left) {
+        &binary_expr.right
+    } else {
+        return;
+    };
+
+    let Expression::CallExpression(call_expr) = index_of.without_parentheses() else {
+        return;
+    };
+    if call_expr.optional || call_expr.arguments.len() != 1 {
+        return;
+    }
+    let Expression::StaticMemberExpression(member_expr) = &call_expr.callee else {
+        return;
+    };
+    if member_expr.optional || member_expr.property.name != "indexOf" {
+        return;
+    }
+    let Some(argument) = call_expr.arguments[0].as_expression() else {
+        return;
+    };
+    if !is_string_expression(&member_expr.object, ctx) {
+        return;
+    }
+
+    let fix = |fixer: RuleFixer<'_, 'a>| {
+        let negation = if matches!(
+            binary_expr.operator,
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality
+        ) {
+            "!"
+        } else {
+            ""
+        };
+        fixer.replace(
+            binary_expr.span,
+            format!(
+                "{negation}{}.startsWith({})",
+                fixer.source_range(member_expr.object.span()),
+                fixer.source_range(argument.span())
+            ),
+        )
+    };
+
+    // Methods are matched by name only, so the receiver may not be a string. And `startsWith`
+    // throws if passed a `RegExp`, where `indexOf` converts it to a string.
+    // So only fix automatically when both are definitely strings.
+    if is_string_value(&member_expr.object, ctx) && is_string_value(argument, ctx) {
+        ctx.diagnostic_with_fix(starts_with_index_of(binary_expr.span), fix);
+    } else {
+        ctx.diagnostic_with_suggestion(starts_with_index_of(binary_expr.span), fix);
+    }
+}
+
+/// Whether `expr` is likely to evaluate to a string primitive.
+///
+/// Calls to string methods are matched by name, so this is not certain.
+/// Use [`is_string_value`] where it needs to be.
+fn is_string_expression(expr: &Expression, ctx: &LintContext) -> bool {
+    if is_string_value(expr, ctx) {
+        return true;
+    }
+    let Expression::CallExpression(call_expr) = expr.without_parentheses() else {
+        return false;
+    };
+    let Expression::StaticMemberExpression(member_expr) = &call_expr.callee else {
+        return false;
+    };
+    !call_expr.optional
+        && !member_expr.optional
+        && matches!(
+            member_expr.property.name.as_str(),
+            "toLowerCase"
+                | "toUpperCase"
+                | "toLocaleLowerCase"
+                | "toLocaleUpperCase"
+                | "trim"
+                | "trimStart"
+                | "trimEnd"
+                | "normalize"
+                | "padStart"
+                | "padEnd"
+        )
+}
+
+/// Whether `expr` always evaluates to a string primitive.
+fn is_string_value(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.without_parentheses() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::CallExpression(call_expr) if !call_expr.optional => {
+            matches!(&call_expr.callee, Expression::Identifier(ident)
+                if ident.name == "String" && ctx.is_reference_to_global_variable(ident))
+        }
+        _ => false,
+    }
+}
+
 fn do_fix<'a>(
     fixer: RuleFixer<'_, 'a>,
     err_kind: ErrorKind,
@@ -201,7 +326,10 @@ fn is_useless_case_sensitive_regex_flag(pattern_text: &str) -> bool {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::{
+        fixer::FixKind,
+        tester::{ExpectFixTestCase, Tester},
+    };
 
     let pass = vec![
         // Unicorn Tests
@@ -236,6 +364,15 @@ fn test() {
         r"/^http/i.test(uri)",
         r"if (/^a/i.test(hex)) {}",
         r"if (/a$/i.test(hex)) {}",
+        r"foo.indexOf('bar') === 0",
+        r"foo.indexOf('bar') === 1",
+        r"foo.toLowerCase().indexOf('bar') === -1",
+        r"foo.toLowerCase().indexOf('bar', 1) === 0",
+        r"foo.toLowerCase().lastIndexOf('bar') === 0",
+        r"foo.toLowerCase()?.indexOf('bar') === 0",
+        r"foo.toLowerCase().indexOf(...bar) === 0",
+        r"foo.toLowerCase().indexOf('bar') > 0",
+        r"const String = Foo; String(foo).indexOf('bar') === 0",
     ];
 
     let fail = vec![
@@ -284,32 +421,55 @@ fn test() {
         r"const a = /^你/.test('a');",
         r"if (/^#/i.test(hex)) {}",
         r"if (/#$/i.test(hex)) {}",
+        r"foo.toLowerCase().indexOf('bar') === 0",
+        r"0 == foo.trim().indexOf(bar)",
+        r"String(foo).indexOf('bar') !== 0",
+        r"`${foo}`.indexOf(bar) != 0",
+        r"'foo'.indexOf(bar) === 0",
     ];
 
-    let fix = vec![
-        ("/^foo/.test(x)", r"x.startsWith('foo')"),
-        ("/foo$/.test(x)", r"x.endsWith('foo')"),
-        ("/^foo/.test(x.y)", r"x.y.startsWith('foo')"),
-        ("/foo$/.test(x.y)", r"x.y.endsWith('foo')"),
-        ("/^foo/.test('x')", r"'x'.startsWith('foo')"),
-        ("/foo$/.test('x')", r"'x'.endsWith('foo')"),
-        ("/^foo/.test(`x${y}`)", r"`x${y}`.startsWith('foo')"),
-        ("/foo$/.test(`x${y}`)", r"`x${y}`.endsWith('foo')"),
-        ("/^foo/.test(String(x))", r"String(x).startsWith('foo')"),
-        ("/foo$/.test(String(x))", r"String(x).endsWith('foo')"),
+    let fix: Vec<ExpectFixTestCase> = vec![
+        ("/^foo/.test(x)", r"x.startsWith('foo')").into(),
+        ("/foo$/.test(x)", r"x.endsWith('foo')").into(),
+        ("/^foo/.test(x.y)", r"x.y.startsWith('foo')").into(),
+        ("/foo$/.test(x.y)", r"x.y.endsWith('foo')").into(),
+        ("/^foo/.test('x')", r"'x'.startsWith('foo')").into(),
+        ("/foo$/.test('x')", r"'x'.endsWith('foo')").into(),
+        ("/^foo/.test(`x${y}`)", r"`x${y}`.startsWith('foo')").into(),
+        ("/foo$/.test(`x${y}`)", r"`x${y}`.endsWith('foo')").into(),
+        ("/^foo/.test(String(x))", r"String(x).startsWith('foo')").into(),
+        ("/foo$/.test(String(x))", r"String(x).endsWith('foo')").into(),
         // https://github.com/oxc-project/oxc/issues/10523
         (
             r"const makePosix = str => /^\\\\\?\\/.test(str)",
             r"const makePosix = str => str.startsWith('\\\\?\\')",
-        ),
-        ("/^'/.test('foo')", r"'foo'.startsWith('\'')"),
-        (r#"/^"/.test('foo')"#, r#"'foo'.startsWith('"')"#),
+        )
+            .into(),
+        ("/^'/.test('foo')", r"'foo'.startsWith('\'')").into(),
+        (r#"/^"/.test('foo')"#, r#"'foo'.startsWith('"')"#).into(),
+        ("foo.toLowerCase().indexOf('bar') === 0", "foo.toLowerCase().startsWith('bar')").into(),
+        ("0 == foo.trim().indexOf(bar)", "foo.trim().startsWith(bar)").into(),
+        ("if (String(foo).indexOf('bar') !== 0) {}", "if (!String(foo).startsWith('bar')) {}")
+            .into(),
+        ("(`${foo}`).indexOf((bar)) != 0", "!(`${foo}`).startsWith((bar))").into(),
+        // only fixed automatically if receiver and argument are both known to be strings
+        ("String(foo).indexOf('bar') === 0", "String(foo).startsWith('bar')", None, FixKind::Fix)
+            .into(),
+        (
+            "foo.toLowerCase().indexOf('bar') === 0",
+            "foo.toLowerCase().indexOf('bar') === 0",
+            None,
+            FixKind::Fix,
+        )
+            .into(),
+        ("'foo'.indexOf(bar) === 0", "'foo'.indexOf(bar) === 0", None, FixKind::Fix).into(),
+        ("'foo'.indexOf(/bar/) === 0", "'foo'.indexOf(/bar/) === 0", None, FixKind::Fix).into(),
         // should not get fixed
-        ("/^foo/.test(new String('bar'))", "/^foo/.test(new String('bar'))"),
-        ("/^foo/.test(x as string)", "/^foo/.test(x as string)"),
-        ("/^foo/.test(5)", "/^foo/.test(5)"),
-        ("/^foo/.test(x?.y)", "/^foo/.test(x?.y)"),
-        ("/^foo/.test(x + y)", "/^foo/.test(x + y)"),
+        ("/^foo/.test(new String('bar'))", "/^foo/.test(new String('bar'))").into(),
+        ("/^foo/.test(x as string)", "/^foo/.test(x as string)").into(),
+        ("/^foo/.test(5)", "/^foo/.test(5)").into(),
+        ("/^foo/.test(x?.y)", "/^foo/.test(x?.y)").into(),
+        ("/^foo/.test(x + y)", "/^foo/.test(x + y)").into(),
     ];
 
     Tester::new(PreferStringStartsEndsWith::NAME, PreferStringStartsEndsWith::PLUGIN, pass, fail)
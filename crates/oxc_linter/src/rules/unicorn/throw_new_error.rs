@@ -57,6 +57,11 @@ impl Rule for ThrowNewError {
             return;
         };
 
+        // `new Error?.()` is a syntax error.
+        if call_expr.optional {
+            return;
+        }
+
         // Skip decorator callees (e.g. @RegisterServiceError()).
         // `new` is not appropriate there.
         let parent_kind = ctx.nodes().parent_kind(node.id());
@@ -74,8 +79,9 @@ impl Rule for ThrowNewError {
             _ => return,
         };
 
-        if name.len() >= 5 && name.as_bytes()[0].is_ascii_uppercase() && name.ends_with("Error") {
-            if matches!(parent_kind, AstKind::Class(_)) {
+        if is_error_constructor_name(&name) {
+            // `new` can't be inserted into a class heritage or an optional chain, e.g. `lib?.Error()`.
+            if matches!(parent_kind, AstKind::Class(_) | AstKind::ChainExpression(_)) {
                 ctx.diagnostic(throw_new_error_diagnostic(call_expr.span));
                 return;
             }
@@ -87,6 +93,16 @@ impl Rule for ThrowNewError {
     }
 }
 
+/// Matches `/^(?:[A-Z][\da-z]*)*Error$/`, e.g. `Error`, `TypeError` and `ABCError`.
+fn is_error_constructor_name(name: &str) -> bool {
+    let Some(prefix) = name.strip_suffix("Error") else {
+        return false;
+    };
+    prefix.is_empty()
+        || (prefix.as_bytes()[0].is_ascii_uppercase()
+            && prefix.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
 fn is_data_tagged_error(callee: &Expression<'_>) -> bool {
     let Expression::StaticMemberExpression(member) = callee else {
         return false;
@@ -117,6 +133,11 @@ fn test() {
         "throw lib[Error]()",
         r#"throw lib["Error"]()"#,
         "throw lib.getError()",
+        "throw Foo_Error()",
+        "throw $Error()",
+        "throw fooError()",
+        "throw Errors()",
+        "throw Error?.()",
         "class QueryError extends Data.TaggedError('QueryError') {}",
         "function RegisterServiceError() {\n    return function <T extends new (...arguments_: any[]) => Error>(constructor: T) {\n        return constructor;\n    };\n}\n\n@RegisterServiceError()\nexport class SomeError extends Error {}",
         "@decorators.RegisterServiceError()\nexport class SomeError extends Error {}",
@@ -135,6 +156,8 @@ fn test() {
         "throw FooBarBazError('foo')",
         "throw ABCError('foo')",
         "throw Abc3Error('foo')",
+        "throw HTTP2Error('foo')",
+        "throw lib?.Error()",
         "throw TypeError()",
         "throw EvalError()",
         "throw RangeError()",
@@ -158,6 +181,9 @@ fn test() {
 
     let fix = vec![
         ("throw Error()", "throw new Error()"),
+        ("throw CustomError('foo')", "throw new CustomError('foo')"),
+        ("throw lib.mod.TypeError()", "throw new lib.mod.TypeError()"),
+        ("throw lib?.Error()", "throw lib?.Error()"),
         ("throw (( getGlobalThis().Error ))()", "throw new (( getGlobalThis().Error ))()"),
         ("const error = Error()", "const error = new Error()"),
         ("throw Object.assign(Error(), {foo})", "throw Object.assign(new Error(), {foo})"),
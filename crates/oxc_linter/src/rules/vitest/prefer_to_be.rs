@@ -42,6 +42,7 @@ fn tests() {
         ("expect(token).toStrictEqual(new RegExp('[abc]+', 'g'));", None),
         ("expect(value).toEqual(dedent`my string`);", None),
         ("expect(0.1 + 0.2).toEqual(0.3);", None),
+        ("expect(0.1 - 0.4).toEqual(-0.3);", None),
         // null
         ("expect(null).toBeNull();", None),
         ("expect(null).not.toBeNull();", None),
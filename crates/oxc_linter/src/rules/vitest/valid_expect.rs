@@ -165,6 +165,9 @@ fn test() {
             "test(\"valid-expect\", async () => { expect(Promise.resolve(2)).toResolve(); });",
             Some(serde_json::json!([{ "asyncMatchers": ["toResolveWith"] }])),
         ),
+        ("test(\"valid-expect\", async () => { await expect(value).toMatchSnapshot(); });", None),
+        ("test(\"valid-expect\", async () => { await expect(value).toBeVisible(); });", None),
+        ("test(\"valid-expect\", async () => { await expect.poll(() => value).toBe(1); });", None),
     ];
 
     let fail = vec![
@@ -364,6 +367,8 @@ fn test() {
             ",
             None,
         ),
+        ("test(\"valid-expect\", async () => { await expect(1).toBe(1); });", None),
+        ("test(\"valid-expect\", async () => { await expect(mock).not.toHaveBeenCalled(); });", None),
     ];
 
     let fix = vec![
@@ -684,6 +689,11 @@ fn test() {
                  "#,
             None,
         ),
+        (
+            "test(\"valid-expect\", async () => { await expect(1).toBe(1); });",
+            "test(\"valid-expect\", async () => { expect(1).toBe(1); });",
+            None,
+        ),
     ];
 
     Tester::new(ValidExpect::NAME, ValidExpect::PLUGIN, pass, fail)
@@ -7,8 +7,11 @@ use std::{
 use rustc_hash::FxHashMap;
 
 use oxc_diagnostics::DiagnosticSender;
+use oxc_sourcemap::SourceMap;
 
-use crate::{Linter, RuleTimingStore, suppression::DiffManager};
+use crate::{
+    Linter, Message, RemappedLocation, RuleTimingStore, source_map, suppression::DiffManager,
+};
 
 mod runtime;
 use runtime::Runtime;
@@ -21,15 +24,30 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Maximum number of times a file is linted and fixed when fixes are enabled.
+    ///
+    /// Applying fixes can expose new fixable problems, or leave behind fixes that were skipped
+    /// because they overlapped with another fix. The fixed code is linted and fixed again until
+    /// no more fixes are applied or this limit is reached.
+    max_fix_passes: usize,
 }
 
+/// Default for [`LintServiceOptions::with_max_fix_passes`], same as ESLint's.
+const DEFAULT_MAX_FIX_PASSES: usize = 10;
+
 impl LintServiceOptions {
     #[must_use]
     pub fn new<T>(cwd: T) -> Self
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            tsconfig: None,
+            cross_module: false,
+            max_fix_passes: DEFAULT_MAX_FIX_PASSES,
+        }
     }
 
     #[inline]
@@ -54,12 +72,50 @@ impl LintServiceOptions {
         self
     }
 
+    /// Set the maximum number of lint and fix passes over each file. Defaults to `10`, same as
+    /// ESLint. `1` applies fixes once without linting the fixed code again.
+    #[inline]
+    #[must_use]
+    pub fn with_max_fix_passes(mut self, max_fix_passes: usize) -> Self {
+        self.max_fix_passes = max_fix_passes.max(1);
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
     }
 }
 
+/// Messages reported for a single file by [`LintService::lint_paths`].
+#[derive(Debug)]
+pub struct FileResult {
+    pub path: Arc<OsStr>,
+    pub messages: Vec<Message>,
+}
+
+impl FileResult {
+    /// Messages that come with a fix or suggestion, e.g. for showing quick fixes in an editor.
+    ///
+    /// Fixes are only attached to messages for the fix kinds enabled with [`Linter::with_fix`].
+    pub fn fixable(&self) -> impl Iterator<Item = &Message> {
+        self.messages.iter().filter(|message| !message.fixes.is_empty())
+    }
+
+    /// Original locations of [`FileResult::messages`] for a generated file (e.g. bundled or
+    /// transpiled code), in the same order.
+    ///
+    /// `source_text` is the linted file's code and `source_map` is its source map. Messages at
+    /// positions with no mapping keep their line and column in the generated file.
+    pub fn remap<'s>(
+        &self,
+        source_text: &str,
+        source_map: &'s SourceMap<'_>,
+    ) -> Vec<RemappedLocation<'s>> {
+        source_map::remap(source_map, source_text, &self.messages)
+    }
+}
+
 pub struct LintService {
     runtime: Runtime,
 }
@@ -97,6 +153,18 @@ impl LintService {
         self.runtime.run_source(file_system, paths)
     }
 
+    /// Read, parse and lint `paths` in parallel, returning the messages for each file.
+    ///
+    /// Each file is parsed into its own allocator, while rules and configuration are shared
+    /// between threads. Results are in the same order as `paths`, with duplicates removed.
+    pub fn lint_paths(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        paths: Vec<Arc<OsStr>>,
+    ) -> Vec<FileResult> {
+        self.runtime.lint_paths(file_system, paths)
+    }
+
     pub fn collect_parse_diagnostics(
         &self,
         file_system: &(dyn RuntimeFileSystem + Sync + Send),
@@ -118,3 +186,359 @@ impl LintService {
         self.runtime.run_test_source(file_system, paths, check_syntax_errors, tx_error)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::OsStr,
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex, mpsc},
+    };
+
+    use rustc_hash::FxHashMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_semantic::AstNode;
+
+    use super::{LintService, LintServiceOptions, RuntimeFileSystem};
+    use crate::{
+        AllowWarnDeny, ConfigStore, ConfigStoreBuilder, CustomRule, ExternalPluginStore, FixKind,
+        LintContext, LintOptions, Linter, Oxlintrc, RuleFixMeta,
+        rules::{EslintNoDebugger, EslintNoUnsafeFinally, RuleEnum},
+        suppression::DiffManager,
+    };
+
+    struct MemoryFileSystem {
+        path: PathBuf,
+        source_text: String,
+        written: Mutex<Option<String>>,
+    }
+
+    impl RuntimeFileSystem for MemoryFileSystem {
+        fn read_to_arena_str<'a>(
+            &self,
+            path: &Path,
+            allocator: &'a Allocator,
+        ) -> Result<&'a str, std::io::Error> {
+            assert_eq!(path, self.path);
+            Ok(allocator.alloc_str(&self.source_text))
+        }
+
+        fn write_file(&self, path: &Path, content: &str) -> Result<(), std::io::Error> {
+            assert_eq!(path, self.path);
+            *self.written.lock().unwrap() = Some(content.to_string());
+            Ok(())
+        }
+    }
+
+    struct SourcesFileSystem(FxHashMap<PathBuf, &'static str>);
+
+    impl RuntimeFileSystem for SourcesFileSystem {
+        fn read_to_arena_str<'a>(
+            &self,
+            path: &Path,
+            allocator: &'a Allocator,
+        ) -> Result<&'a str, std::io::Error> {
+            Ok(allocator.alloc_str(self.0[path]))
+        }
+
+        fn write_file(&self, _path: &Path, _content: &str) -> Result<(), std::io::Error> {
+            unreachable!("`lint_paths` does not apply fixes")
+        }
+    }
+
+    /// A linter with `rules` enabled as warnings.
+    fn linter(rules: impl IntoIterator<Item = RuleEnum>) -> Linter {
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = rules
+            .into_iter()
+            .fold(ConfigStoreBuilder::empty(), |builder, rule| {
+                builder.with_rule(rule, AllowWarnDeny::Warn)
+            })
+            .build(&mut external_plugin_store)
+            .unwrap();
+        Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        )
+    }
+
+    fn no_debugger_linter() -> Linter {
+        linter([RuleEnum::EslintNoDebugger(EslintNoDebugger)])
+    }
+
+    /// Replaces `a` with `b` and `b` with `a`, so its fixes never converge.
+    #[derive(Debug)]
+    struct SwapAB;
+
+    impl CustomRule for SwapAB {
+        fn plugin_name(&self) -> &'static str {
+            "custom"
+        }
+
+        fn name(&self) -> &'static str {
+            "swap-a-b"
+        }
+
+        fn fix(&self) -> RuleFixMeta {
+            RuleFixMeta::Fixable(FixKind::SafeFix)
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            if let AstKind::IdentifierReference(ident) = node.kind() {
+                let replacement = match ident.name.as_str() {
+                    "a" => "b",
+                    "b" => "a",
+                    _ => return,
+                };
+                ctx.diagnostic_with_fix(
+                    OxcDiagnostic::warn("Unexpected identifier").with_label(ident.span),
+                    |fixer| fixer.replace(ident.span, replacement),
+                );
+            }
+        }
+    }
+
+    /// Replaces `debugger` statements with `foo();`, conflicting with the fix of `no-debugger`.
+    #[derive(Debug)]
+    struct DebuggerToFoo;
+
+    impl CustomRule for DebuggerToFoo {
+        fn plugin_name(&self) -> &'static str {
+            "custom"
+        }
+
+        fn name(&self) -> &'static str {
+            "debugger-to-foo"
+        }
+
+        fn fix(&self) -> RuleFixMeta {
+            RuleFixMeta::Fixable(FixKind::SafeFix)
+        }
+
+        fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+            if let AstKind::DebuggerStatement(stmt) = node.kind() {
+                ctx.diagnostic_with_fix(
+                    OxcDiagnostic::warn("Unexpected debugger").with_label(stmt.span),
+                    |fixer| fixer.replace(stmt.span, "foo();"),
+                );
+            }
+        }
+    }
+
+    /// Lint `source_text` with `no-debugger`, [`SwapAB`] and all fixes enabled, returning the
+    /// written file contents and the number of diagnostics reported.
+    fn fix(source_text: &str, max_fix_passes: usize) -> (Option<String>, usize) {
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("fix_passes.js");
+
+        let linter = no_debugger_linter()
+            .with_custom_rules([(Box::new(SwapAB) as Box<dyn CustomRule>, AllowWarnDeny::Warn)])
+            .with_fix(FixKind::All);
+        let options = LintServiceOptions::new(cwd).with_max_fix_passes(max_fix_passes);
+        let service = LintService::new(linter, options);
+
+        let file_system = MemoryFileSystem {
+            path: path.clone(),
+            source_text: source_text.to_string(),
+            written: Mutex::new(None),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let diff_manager = Arc::new(DiffManager::new(Arc::default(), false, true, false));
+        service.run::<false>(
+            &file_system,
+            vec![Arc::<OsStr>::from(path.as_os_str())],
+            &sender,
+            &diff_manager,
+            None,
+        );
+        drop(sender);
+
+        let diagnostics = receiver.iter().map(|diagnostics| diagnostics.len()).sum();
+        (file_system.written.into_inner().unwrap(), diagnostics)
+    }
+
+    #[test]
+    fn test_fix_passes() {
+        // Adjacent fixes overlap, so a single pass only removes the first statement.
+        let source_text = "debugger;debugger;";
+        assert_eq!(fix(source_text, 1), (Some("debugger;".to_string()), 1));
+        assert_eq!(fix(source_text, 10), (Some(String::new()), 0));
+
+        // The number of passes is capped. Each pass removes every other statement.
+        let source_text = "debugger;debugger;debugger;debugger;";
+        assert_eq!(fix(source_text, 2), (Some("debugger;".to_string()), 1));
+        assert_eq!(fix(source_text, 3), (Some(String::new()), 0));
+
+        // Nothing to fix.
+        assert_eq!(fix("foo();", 10), (None, 0));
+
+        // Fixes which undo each other stop once the code repeats, instead of running until the
+        // limit is reached. The problem in the code left behind is still reported.
+        assert_eq!(fix("a;", 10), (Some("b;".to_string()), 1));
+        assert_eq!(fix("a;", 11), (Some("b;".to_string()), 1));
+    }
+
+    /// Lint `source_text` with `no-debugger`, [`DebuggerToFoo`] and all fixes enabled, returning
+    /// the written file contents and the notes of the diagnostics reported.
+    fn fix_conflicts(source_text: &str, max_fix_passes: usize) -> (Option<String>, Vec<String>) {
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("fix_conflicts.js");
+
+        let linter = no_debugger_linter()
+            .with_custom_rules([(
+                Box::new(DebuggerToFoo) as Box<dyn CustomRule>,
+                AllowWarnDeny::Warn,
+            )])
+            .with_fix(FixKind::All);
+        let options = LintServiceOptions::new(cwd).with_max_fix_passes(max_fix_passes);
+        let service = LintService::new(linter, options);
+
+        let file_system = MemoryFileSystem {
+            path: path.clone(),
+            source_text: source_text.to_string(),
+            written: Mutex::new(None),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let diff_manager = Arc::new(DiffManager::new(Arc::default(), false, true, false));
+        service.run::<false>(
+            &file_system,
+            vec![Arc::<OsStr>::from(path.as_os_str())],
+            &sender,
+            &diff_manager,
+            None,
+        );
+        drop(sender);
+
+        let notes = receiver
+            .iter()
+            .flatten()
+            .map(|diagnostic| diagnostic.note().map(|note| note.to_string()).unwrap_or_default())
+            .collect();
+        (file_system.written.into_inner().unwrap(), notes)
+    }
+
+    #[test]
+    fn test_fix_conflicts() {
+        let note = "The fix for this problem was not applied because it overlaps a fix from `custom/debugger-to-foo`.";
+
+        // Fixes for the same span are ordered by rule name, so `custom/debugger-to-foo` wins and
+        // the skipped `no-debugger` problem is reported with a note about the conflict.
+        assert_eq!(fix_conflicts("debugger;", 1), (Some("foo();".to_string()), vec![note.into()]));
+
+        // Conflicts are noted on problems left by the last pass when fixing in multiple passes.
+        // Adjacent fixes overlap, so the first pass fixes every other statement.
+        assert_eq!(
+            fix_conflicts("debugger;debugger;debugger;", 2),
+            (Some("foo();foo();foo();".to_string()), vec![note.into()])
+        );
+
+        // Conflicts which are resolved by a later pass are not reported.
+        assert_eq!(fix_conflicts("debugger;", 10), (Some("foo();".to_string()), vec![]));
+    }
+
+    #[test]
+    fn test_lint_paths() {
+        let cwd = std::env::current_dir().unwrap();
+        let sources = [
+            ("a.js", "debugger;"),
+            ("b.js", "foo();"),
+            ("c.js", "debugger; if (foo) { debugger; }"),
+            ("d.js", "let = ;"),
+            ("e.ts", "const x: number = 1; debugger;"),
+        ];
+        let file_system = SourcesFileSystem(
+            sources.iter().map(|(name, source_text)| (cwd.join(name), *source_text)).collect(),
+        );
+        let paths = sources
+            .iter()
+            .map(|(name, _)| Arc::<OsStr>::from(cwd.join(name).into_os_string()))
+            .collect::<Vec<_>>();
+
+        // Files are linted on different threads, but the results should always be the same.
+        for _ in 0..5 {
+            let service = LintService::new(no_debugger_linter(), LintServiceOptions::new(&*cwd));
+            let results = service.lint_paths(&file_system, paths.clone());
+
+            assert_eq!(
+                results.iter().map(|result| &result.path).collect::<Vec<_>>(),
+                paths.iter().collect::<Vec<_>>()
+            );
+            let counts = results.iter().map(|result| result.messages.len()).collect::<Vec<_>>();
+            // `d.js` has a syntax error.
+            assert_eq!(counts, [1, 0, 2, 1, 1]);
+            assert!(results[2].messages.iter().all(|message| {
+                message.rule.as_ref().is_some_and(|rule| rule.rule_name == "no-debugger")
+            }));
+        }
+    }
+
+    #[test]
+    fn test_fixable() {
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("fixable.js");
+        let file_system = SourcesFileSystem(FxHashMap::from_iter([(
+            path.clone(),
+            "debugger; function foo() { try {} finally { return 1; } } debugger;",
+        )]));
+
+        let linter = linter([
+            RuleEnum::EslintNoDebugger(EslintNoDebugger),
+            RuleEnum::EslintNoUnsafeFinally(EslintNoUnsafeFinally),
+        ])
+        .with_fix(FixKind::All);
+        let service = LintService::new(linter, LintServiceOptions::new(&*cwd));
+        let results =
+            service.lint_paths(&file_system, vec![Arc::<OsStr>::from(path.into_os_string())]);
+
+        // `no-debugger` has a fix, `no-unsafe-finally` does not.
+        assert_eq!(results[0].messages.len(), 3);
+        let fixable = results[0].fixable().collect::<Vec<_>>();
+        assert_eq!(fixable.len(), 2);
+        assert!(fixable.iter().all(|message| {
+            message.rule.as_ref().is_some_and(|rule| rule.rule_name == "no-debugger")
+        }));
+    }
+
+    #[test]
+    fn test_ignore_comment_prefixes() {
+        let cwd = std::env::current_dir().unwrap();
+        let path = cwd.join("ignore_comment_prefixes.js");
+        let file_system = SourcesFileSystem(FxHashMap::from_iter([(
+            path.clone(),
+            "debugger; // oxlint-ignore no-debugger\n// oxlint-ignore no-debugger\ndebugger;",
+        )]));
+
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": { "no-debugger": "warn" },
+            "options": { "ignoreCommentPrefixes": ["oxlint-ignore"] }
+        }))
+        .unwrap();
+        let mut external_plugin_store = ExternalPluginStore::default();
+        let config = ConfigStoreBuilder::from_oxlintrc(
+            true,
+            oxlintrc,
+            None,
+            &mut external_plugin_store,
+            None,
+        )
+        .unwrap()
+        .build(&mut external_plugin_store)
+        .unwrap();
+        let linter = Linter::new(
+            LintOptions::default(),
+            ConfigStore::new(config, FxHashMap::default(), external_plugin_store),
+            None,
+        );
+        let service = LintService::new(linter, LintServiceOptions::new(&*cwd));
+        let results =
+            service.lint_paths(&file_system, vec![Arc::<OsStr>::from(path.into_os_string())]);
+
+        // Only the comment on the same line suppresses the diagnostic.
+        assert_eq!(results[0].messages.len(), 1);
+        assert_eq!(results[0].messages[0].span.start, 68);
+    }
+}
@@ -2,7 +2,7 @@ use std::{
     borrow::Cow,
     ffi::OsStr,
     fs,
-    hash::BuildHasherDefault,
+    hash::{BuildHasher, BuildHasherDefault},
     mem::take,
     path::{Path, PathBuf},
     sync::{Arc, Mutex, mpsc},
@@ -21,7 +21,7 @@ use smallvec::SmallVec;
 
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool, ArenaBox};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
-use oxc_parser::{ParseOptions, Parser, Token, config::RuntimeParserConfig};
+use oxc_parser::{Parser, Token, config::RuntimeParserConfig};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
@@ -33,11 +33,12 @@ use crate::{
     disable_directives::DisableDirectives,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
     module_record::ModuleRecord,
+    parse_options,
     suppression::DiffManager,
     utils::read_to_arena_str,
 };
 
-use super::LintServiceOptions;
+use super::{FileResult, LintServiceOptions};
 
 type ModulesByPath =
     papaya::HashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>, BuildHasherDefault<FxHasher>>;
@@ -66,6 +67,8 @@ pub struct Runtime {
     modules_by_path: ModulesByPath,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
+    /// See [`LintServiceOptions::with_max_fix_passes`].
+    max_fix_passes: usize,
 }
 
 /// Output of `Runtime::process_path`
@@ -263,6 +266,7 @@ impl Runtime {
                 .resize_mode(papaya::ResizeMode::Blocking)
                 .build(),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
+            max_fix_passes: options.max_fix_passes,
         }
     }
 
@@ -613,6 +617,7 @@ impl Runtime {
                 true,
                 Some(tx_error),
                 move |me, mut module_to_lint| {
+                    let mut fixed_code = None;
                     module_to_lint.content.with_dependent_mut(|allocator_guard, dep| {
                         // If there are fixes, we will accumulate all of them and write to the file at the end.
                         // This means we do not write multiple times to the same file if there are multiple sources
@@ -628,6 +633,8 @@ impl Runtime {
 
                         let respect_eslint_disable_directives =
                             me.linter.respect_eslint_disable_directives();
+
+                        let ignore_comment_prefixes = me.linter.ignore_comment_prefixes();
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
@@ -641,6 +648,9 @@ impl Runtime {
                                         framework_options: section.source.framework_options,
                                         parser_tokens: section.parser_tokens,
                                         respect_eslint_disable_directives,
+                                        ignore_comment_prefixes: Arc::clone(
+                                            ignore_comment_prefixes,
+                                        ),
                                         ..Default::default()
                                     },
                                 )),
@@ -681,7 +691,7 @@ impl Runtime {
                         }
 
                         if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(
+                            let mut fix_result = Fixer::new(
                                 dep.source_text,
                                 messages,
                                 SourceType::from_path(path).ok().map(|st| {
@@ -689,6 +699,15 @@ impl Runtime {
                                 }),
                             )
                             .fix();
+                            if fix_result.fixed && me.max_fix_passes > 1 {
+                                // Lint the fixed code again once this module's allocator has
+                                // been returned to the pool.
+                                fixed_code = Some((
+                                    FxBuildHasher.hash_one(dep.source_text),
+                                    fix_result.fixed_code.into_owned(),
+                                ));
+                                return;
+                            }
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
                                 let start = 0;
@@ -698,6 +717,7 @@ impl Runtime {
                                     .replace_range(start..end, &fix_result.fixed_code);
                             }
 
+                            fix_result.note_conflicts();
                             messages = fix_result.messages;
                         }
 
@@ -729,11 +749,178 @@ impl Runtime {
                                 .unwrap();
                         }
                     });
+
+                    if let Some((source_hash, fixed_code)) = fixed_code {
+                        drop(module_to_lint.content);
+                        me.run_fix_passes::<TIMINGS>(
+                            file_system,
+                            Path::new(&module_to_lint.path),
+                            source_hash,
+                            fixed_code,
+                            tx_error,
+                            diff_manager,
+                            rule_timing_store,
+                        );
+                    }
                 },
             );
         });
     }
 
+    /// Lint and fix source text which has already been fixed once, until no more fixes are
+    /// applied or [`Runtime::max_fix_passes`] is reached. Then report the problems left by the
+    /// last pass and write the fixed code to the file system.
+    fn run_fix_passes<const TIMINGS: bool>(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        path: &Path,
+        source_hash: u64,
+        mut source_text: String,
+        tx_error: &DiagnosticSender,
+        diff_manager: &DiffManager,
+        rule_timing_store: Option<&RuleTimingStore>,
+    ) {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let source_type = SourceType::from_path(path)
+            .ok()
+            .map(|st| if st.is_javascript() { st.with_jsx(true) } else { st });
+
+        // The first pass was done by the caller.
+        let mut passes = 1;
+        // Hashes of the code before and after each pass, to detect fixes which undo each other.
+        let mut seen_hashes =
+            FxHashSet::from_iter([source_hash, FxBuildHasher.hash_one(&source_text)]);
+        // Fixed code of the last pass, if the limit was reached.
+        let mut fixed_source_text = None;
+        let mut messages = loop {
+            let allocator_guard = self.allocator_pool.get();
+            let allocator = &*allocator_guard;
+
+            let mut section_contents = SectionContents::new();
+            let records = self.process_source(
+                path,
+                ext,
+                true,
+                source_type.unwrap_or_default(),
+                &source_text,
+                allocator,
+                Some(&mut section_contents),
+            );
+
+            let respect_eslint_disable_directives = self.linter.respect_eslint_disable_directives();
+
+            let ignore_comment_prefixes = self.linter.ignore_comment_prefixes();
+            let mut context_sub_hosts = Vec::with_capacity(section_contents.len());
+            for (record_result, section) in records.into_iter().zip(section_contents) {
+                match record_result {
+                    Ok(record) => {
+                        self.link_loaded_modules(&record);
+                        context_sub_hosts.push(ContextSubHost::new(
+                            section.semantic.unwrap(),
+                            record.module_record,
+                            section.source.start,
+                            ContextSubHostOptions {
+                                framework_options: section.source.framework_options,
+                                parser_tokens: section.parser_tokens,
+                                respect_eslint_disable_directives,
+                                ignore_comment_prefixes: Arc::clone(ignore_comment_prefixes),
+                                ..Default::default()
+                            },
+                        ));
+                    }
+                    Err(messages) => {
+                        if !messages.is_empty() {
+                            let diagnostics = DiagnosticService::wrap_diagnostics(
+                                &self.cwd,
+                                path,
+                                &source_text,
+                                messages,
+                            );
+                            tx_error.send(diagnostics).unwrap();
+                        }
+                    }
+                }
+            }
+
+            if context_sub_hosts.is_empty() {
+                break vec![];
+            }
+
+            let (messages, disable_directives) =
+                self.linter.run_with_disable_directives::<TIMINGS>(
+                    path,
+                    context_sub_hosts,
+                    allocator,
+                    self.js_allocator_pool(),
+                    rule_timing_store,
+                );
+
+            if let Some(disable_directives) = disable_directives {
+                self.disable_directives_map
+                    .lock()
+                    .expect("disable_directives_map mutex poisoned")
+                    .insert(path.to_path_buf(), disable_directives);
+            }
+
+            let mut fix_result = Fixer::new(&source_text, messages.clone(), source_type).fix();
+            // Stop once fixes produce code seen before, so fixes which undo each other can't
+            // keep the loop going until the limit is reached.
+            if !fix_result.fixed
+                || !seen_hashes.insert(FxBuildHasher.hash_one(fix_result.fixed_code.as_ref()))
+            {
+                break messages;
+            }
+            passes += 1;
+            if passes >= self.max_fix_passes {
+                // Last pass. Report the problems which were not fixed, same as a single pass.
+                fix_result.note_conflicts();
+                fixed_source_text = Some(fix_result.fixed_code.into_owned());
+                break fix_result.messages;
+            }
+            source_text = fix_result.fixed_code.into_owned();
+        };
+
+        if !diff_manager.skip() {
+            messages = diff_manager.collect_file(path, &self.cwd, messages);
+        }
+
+        if !messages.is_empty() {
+            let errors = messages.into_iter().map(Into::into).collect();
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(&self.cwd, path, &source_text, errors);
+            tx_error.send(diagnostics).unwrap();
+        }
+
+        let source_text = fixed_source_text.unwrap_or(source_text);
+        if let Err(error) = file_system.write_file(path, &source_text) {
+            tx_error
+                .send(vec![Error::new(OxcDiagnostic::error(format!(
+                    "Failed to write file {} with error \"{error}\"",
+                    path.display()
+                )))])
+                .unwrap();
+        }
+    }
+
+    /// Populate `loaded_modules` of a module record created after the module graph was built,
+    /// from the modules already in the graph.
+    fn link_loaded_modules(&self, record: &ResolvedModuleRecord) {
+        if record.resolved_module_requests.is_empty() {
+            return;
+        }
+        let modules_by_path = self.modules_by_path.pin();
+        let mut loaded_modules = record.module_record.write_loaded_modules();
+        for request in &record.resolved_module_requests {
+            // Modules imported only by the fixed code are not part of the module graph.
+            if let Some(dep_module_record) = modules_by_path
+                .get(&request.resolved_requested_path)
+                .and_then(|records| records.last())
+            {
+                loaded_modules.insert(request.specifier.clone(), Arc::downgrade(dep_module_record));
+            }
+        }
+    }
+
     // language_server: the language server needs line and character position
     // the struct not using `oxc_diagnostic::Error, because we are just collecting information
     // and returning it to the client to let him display it.
@@ -742,12 +929,18 @@ impl Runtime {
         file_system: &(dyn RuntimeFileSystem + Sync + Send),
         paths: Vec<Arc<OsStr>>,
     ) -> Vec<Message> {
-        use std::sync::Mutex;
+        self.lint_paths(file_system, paths).into_iter().flat_map(|result| result.messages).collect()
+    }
 
+    pub(super) fn lint_paths(
+        &self,
+        file_system: &(dyn RuntimeFileSystem + Sync + Send),
+        paths: Vec<Arc<OsStr>>,
+    ) -> Vec<FileResult> {
         self.modules_by_path.pin().reserve(paths.len());
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
-        let messages = Mutex::new(Vec::<Message>::new());
+        let messages_by_path = Mutex::new(FxHashMap::<Arc<OsStr>, Vec<Message>>::default());
         rayon::scope(|scope| {
             self.resolve_modules(
                 file_system,
@@ -766,6 +959,8 @@ impl Runtime {
 
                             let respect_eslint_disable_directives =
                                 me.linter.respect_eslint_disable_directives();
+
+                            let ignore_comment_prefixes = me.linter.ignore_comment_prefixes();
                             let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                                 .section_module_records
                                 .into_iter()
@@ -779,16 +974,20 @@ impl Runtime {
                                             framework_options: section.source.framework_options,
                                             parser_tokens: section.parser_tokens,
                                             respect_eslint_disable_directives,
+                                            ignore_comment_prefixes: Arc::clone(ignore_comment_prefixes),
                                             ..Default::default()
                                         },
                                     )),
                                     Err(diagnostics) => {
                                         if !diagnostics.is_empty() {
-                                            messages.lock().unwrap().extend(
-                                                diagnostics.into_iter().map(|diagnostic| {
+                                            messages_by_path
+                                                .lock()
+                                                .unwrap()
+                                                .entry(Arc::clone(&module_to_lint.path))
+                                                .or_default()
+                                                .extend(diagnostics.into_iter().map(|diagnostic| {
                                                     Message::new(diagnostic, PossibleFixes::None)
-                                                }),
-                                            );
+                                                }));
                                         }
                                         None
                                     }
@@ -817,14 +1016,27 @@ impl Runtime {
                                     .insert(path.to_path_buf(), disable_directives);
                             }
 
-                            messages.lock().unwrap().extend(section_messages);
+                            messages_by_path
+                                .lock()
+                                .unwrap()
+                                .entry(Arc::clone(&module_to_lint.path))
+                                .or_default()
+                                .extend(section_messages);
                         },
                     );
                 },
             );
         });
 
-        messages.into_inner().unwrap()
+        // Return results in the order the paths were given, regardless of which thread finished first.
+        let mut messages_by_path = messages_by_path.into_inner().unwrap();
+        paths_set
+            .into_iter()
+            .map(|path| {
+                let messages = messages_by_path.remove(&path).unwrap_or_default();
+                FileResult { path, messages }
+            })
+            .collect()
     }
 
     pub(super) fn collect_parse_diagnostics(
@@ -909,6 +1121,8 @@ impl Runtime {
 
                         let respect_eslint_disable_directives =
                             me.linter.respect_eslint_disable_directives();
+
+                        let ignore_comment_prefixes = me.linter.ignore_comment_prefixes();
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module
                             .section_module_records
                             .into_iter()
@@ -922,6 +1136,7 @@ impl Runtime {
                                         framework_options: section.source.framework_options,
                                         parser_tokens: section.parser_tokens,
                                         respect_eslint_disable_directives,
+                                        ignore_comment_prefixes: Arc::clone(ignore_comment_prefixes),
                                         ..Default::default()
                                     },
                                 )),
@@ -1125,11 +1340,7 @@ impl Runtime {
     {
         let collect_tokens = self.linter.has_external_linter();
         let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
+            .with_options(parse_options())
             .with_config(RuntimeParserConfig::new(collect_tokens))
             .parse();
 
@@ -0,0 +1,116 @@
+//! Remapping of diagnostic positions in generated code (e.g. bundled or transpiled files)
+//! to their positions in the original sources, using the generated file's source map.
+
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_sourcemap::SourceMap;
+
+use crate::Message;
+
+/// Position of a diagnostic after remapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemappedLocation<'s> {
+    /// Original source file, as listed in the source map's `sources`.
+    ///
+    /// `None` if the position has no mapping, in which case `line` and `column`
+    /// are the position in the generated file.
+    pub source: Option<&'s str>,
+    /// 0-based line.
+    pub line: u32,
+    /// 0-based column, in UTF-16 code units (as in source maps).
+    pub column: u32,
+}
+
+/// Get the original location of the start of each message's span.
+///
+/// `source_text` is the generated code that `messages` were reported on.
+/// A position is mapped using the closest mapping at or before it on the same line.
+/// Positions which have no mapping keep their position in the generated file.
+pub fn remap<'s>(
+    source_map: &'s SourceMap<'_>,
+    source_text: &str,
+    messages: &[Message],
+) -> Vec<RemappedLocation<'s>> {
+    let lookup_table = source_map.generate_lookup_table();
+    let rope = Rope::from_str(source_text);
+
+    messages
+        .iter()
+        .map(|message| {
+            let (line, column) = get_line_column(&rope, message.span.start, source_text);
+            match source_map.lookup_source_view_token(&lookup_table, line, column) {
+                Some(token) if token.get_source().is_some() => RemappedLocation {
+                    source: token.get_source(),
+                    line: token.get_src_line(),
+                    column: token.get_src_col(),
+                },
+                _ => RemappedLocation { source: None, line, column },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_sourcemap::SourceMap;
+    use oxc_span::Span;
+
+    use super::{RemappedLocation, remap};
+    use crate::{Message, PossibleFixes};
+
+    fn message_at(start: u32, end: u32) -> Message {
+        Message::new(
+            OxcDiagnostic::warn("test").with_label(Span::new(start, end)),
+            PossibleFixes::None,
+        )
+    }
+
+    #[test]
+    fn remap_to_original_location() {
+        // Generated from 2 files:
+        // `a.ts`: "const a: number = 1;\n"
+        // `b.ts`: "\n\nlet b = 2;\n"
+        let generated = "const a = 1;\nlet b = 2;\nfoo();\n";
+        let source_map = r#"{
+            "version": 3,
+            "sources": ["a.ts", "b.ts"],
+            "names": [],
+            "mappings": "AAAA,MAAM,CAAC,GAAW;ACElB,IAAI;"
+        }"#;
+        let source_map = SourceMap::from_json_string(source_map).unwrap();
+
+        let messages = [
+            // `a` in `const a = 1;`
+            message_at(6, 7),
+            // `1` in `const a = 1;`
+            message_at(10, 11),
+            // `b` in `let b = 2;`
+            message_at(17, 18),
+            // `foo` has no mapping
+            message_at(24, 27),
+        ];
+        assert_eq!(
+            remap(&source_map, generated, &messages),
+            vec![
+                RemappedLocation { source: Some("a.ts"), line: 0, column: 6 },
+                RemappedLocation { source: Some("a.ts"), line: 0, column: 18 },
+                RemappedLocation { source: Some("b.ts"), line: 2, column: 4 },
+                RemappedLocation { source: None, line: 2, column: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn columns_are_utf16() {
+        let generated = "'😀'; x;";
+        // Generated columns 0, 6 and 8 map to columns 0, 2 and 9 in `a.js`
+        let source_map =
+            r#"{"version":3,"sources":["a.js"],"names":[],"mappings":"AAAA,MAAE,EAAO"}"#;
+        let source_map = SourceMap::from_json_string(source_map).unwrap();
+        // `x` is at byte offset 8, but UTF-16 column 6
+        assert_eq!(
+            remap(&source_map, generated, &[message_at(8, 9)]),
+            vec![RemappedLocation { source: Some("a.js"), line: 0, column: 2 }]
+        );
+    }
+}
@@ -30,7 +30,8 @@ use crate::{
 #[derive(Eq, PartialEq)]
 enum TestResult {
     Passed,
-    Failed,
+    /// Number of diagnostics reported
+    Failed(usize),
     Fixed(String),
 }
 
@@ -40,6 +41,8 @@ pub struct TestCase {
     rule_config: Option<Value>,
     eslint_config: Option<Value>,
     path: Option<PathBuf>,
+    /// Expected number of diagnostics. Only checked for failing test cases.
+    error_count: Option<usize>,
 }
 
 impl From<&str> for TestCase {
@@ -75,7 +78,7 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
             Option<PathBuf>,
         ),
     ) -> Self {
-        Self { source: source.to_string(), rule_config, eslint_config, path }
+        Self { source: source.to_string(), rule_config, eslint_config, path, error_count: None }
     }
 }
 
@@ -182,6 +185,21 @@ impl<S: Into<String>> From<(S, (S, S))> for ExpectFixTestCase {
     }
 }
 
+impl<S: Into<String>> From<(S, (S, S), Option<Value>)> for ExpectFixTestCase {
+    fn from(value: (S, (S, S), Option<Value>)) -> Self {
+        Self {
+            source: value.0.into(),
+            expected: vec![
+                ExpectFix { expected: value.1.0.into(), kind: ExpectFixKind::Any },
+                ExpectFix { expected: value.1.1.into(), kind: ExpectFixKind::Any },
+            ],
+            rule_config: value.2,
+            path: None,
+            eslint_config: None,
+        }
+    }
+}
+
 impl<S: Into<String>> From<(S, (S, S, S))> for ExpectFixTestCase {
     fn from(value: (S, (S, S, S))) -> Self {
         Self {
@@ -265,6 +283,56 @@ impl<S: Into<String>> From<(S, S, Option<Value>, Option<PathBuf>, Option<Value>)
     }
 }
 
+/// Rule test cases in the format of ESLint's `RuleTester`.
+///
+/// Only `code`, `options`, `errors` and `output` are used. Other properties are ignored.
+#[derive(Deserialize)]
+struct EslintFixture {
+    #[serde(default)]
+    valid: Vec<EslintValidCase>,
+    #[serde(default)]
+    invalid: Vec<EslintInvalidCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EslintValidCase {
+    Code(String),
+    Object {
+        code: String,
+        #[serde(default)]
+        options: Option<Value>,
+    },
+}
+
+#[derive(Deserialize)]
+struct EslintInvalidCase {
+    code: String,
+    #[serde(default)]
+    options: Option<Value>,
+    errors: EslintErrors,
+    /// Code after fixes are applied. `null` or same as `code` if no fix is expected.
+    #[serde(default)]
+    output: Option<String>,
+}
+
+/// Either the number of errors, or an array describing each error.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EslintErrors {
+    Count(usize),
+    List(Vec<Value>),
+}
+
+impl EslintErrors {
+    fn count(&self) -> usize {
+        match self {
+            Self::Count(count) => *count,
+            Self::List(errors) => errors.len(),
+        }
+    }
+}
+
 struct TesterFileSystem {
     path_to_lint: PathBuf,
     source_text: String,
@@ -344,6 +412,65 @@ impl Tester {
         }
     }
 
+    /// Create a [`Tester`] from a JSON file of test cases in the format of ESLint's `RuleTester`,
+    /// i.e. `{ "valid": [...], "invalid": [...] }`.
+    ///
+    /// `fixture_path` is relative to `crates/oxc_linter/fixtures`.
+    ///
+    /// Each valid case is either a string of code, or an object with `code` and optional `options`.
+    /// Each invalid case is an object with `code`, optional `options`, `errors` (an array or a count),
+    /// and optional `output`. The number of diagnostics must match `errors`, and an `output`
+    /// which differs from `code` is checked as a fix.
+    pub fn from_eslint_fixture(
+        rule_name: &'static str,
+        plugin_name: &'static str,
+        fixture_path: &str,
+    ) -> Self {
+        let path = env::current_dir().unwrap().join("fixtures").join(fixture_path);
+        let json = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+        let fixture: EslintFixture = serde_json::from_str(&json)
+            .unwrap_or_else(|err| panic!("invalid fixture {}: {err}", path.display()));
+
+        let pass = fixture
+            .valid
+            .into_iter()
+            .map(|case| match case {
+                EslintValidCase::Code(source) => TestCase::from(source),
+                EslintValidCase::Object { code, options } => {
+                    TestCase { source: code, rule_config: options, ..TestCase::default() }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut fix = vec![];
+        let fail = fixture
+            .invalid
+            .into_iter()
+            .map(|case| {
+                if let Some(output) = case.output.filter(|output| *output != case.code) {
+                    fix.push(ExpectFixTestCase::from((
+                        case.code.clone(),
+                        output,
+                        case.options.clone(),
+                    )));
+                }
+                TestCase {
+                    source: case.code,
+                    rule_config: case.options,
+                    error_count: Some(case.errors.count()),
+                    ..TestCase::default()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut tester = Self::new(rule_name, plugin_name, pass, fail);
+        if !fix.is_empty() {
+            tester.expect_fix = Some(fix);
+        }
+        tester
+    }
+
     /// Change the path
     pub fn change_rule_path(mut self, path: &str) -> Self {
         self.rule_path = self.current_working_directory.join(path);
@@ -450,7 +577,10 @@ impl Tester {
     #[expect(clippy::print_stdout)]
     pub fn test(&mut self) {
         let failed = self.test_pass();
-        let passed = self.test_fail();
+        let (wrong_error_counts, passed): (Vec<_>, Vec<_>) = self
+            .test_fail()
+            .into_iter()
+            .partition(|failure| matches!(failure, TestFailure::WrongErrorCount { .. }));
         let fix_failures = self.test_fix();
 
         if !failed.is_empty() {
@@ -459,12 +589,21 @@ impl Tester {
         if !passed.is_empty() {
             println!("{}", format_test_failures("expected to fail, but passed", &passed));
         }
+        if !wrong_error_counts.is_empty() {
+            println!(
+                "{}",
+                format_test_failures("reported the wrong number of errors", &wrong_error_counts)
+            );
+        }
         if !fix_failures.is_empty() {
             println!("{}", format_fix_failures(&fix_failures));
         }
 
         assert!(
-            failed.is_empty() && passed.is_empty() && fix_failures.is_empty(),
+            failed.is_empty()
+                && passed.is_empty()
+                && wrong_error_counts.is_empty()
+                && fix_failures.is_empty(),
             "Some tests failed for rule {}/{} (see output above)",
             self.plugin_name,
             self.rule_name
@@ -504,7 +643,7 @@ impl Tester {
         let rule_has_config = rule.has_config();
         let rule_name = format!("{}/{}", rule.plugin_name(), rule.name());
 
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_pass.clone() {
+        for TestCase { source, rule_config, eslint_config, path, .. } in self.expect_pass.clone() {
             assert!(
                 rule_config.is_none() || rule_has_config,
                 "Rule {rule_name} has no config schema, but a rule config was provided in the test case.\n{rule_config:?}"
@@ -531,16 +670,29 @@ impl Tester {
         let rule_name = format!("{}/{}", rule.plugin_name(), rule.name());
 
         let mut passed = vec![];
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_fail.clone() {
+        for TestCase { source, rule_config, eslint_config, path, error_count } in
+            self.expect_fail.clone()
+        {
             assert!(
                 rule_config.is_none() || rule_has_config,
                 "Rule {rule_name} has no config schema, but a rule config was provided in the test case.\n{rule_config:?}"
             );
             let result =
                 self.run(&source, rule_config.clone(), eslint_config, path, ExpectFixKind::None, 0);
-            let failed = result == TestResult::Failed;
-            if !failed {
-                passed.push(TestFailure::ExpectedToFail { source, rule_config });
+            match result {
+                TestResult::Failed(count) => {
+                    if let Some(expected) = error_count
+                        && expected != count
+                    {
+                        passed.push(TestFailure::WrongErrorCount {
+                            source,
+                            rule_config,
+                            expected,
+                            actual: count,
+                        });
+                    }
+                }
+                _ => passed.push(TestFailure::ExpectedToFail { source, rule_config }),
             }
         }
         passed
@@ -599,7 +751,7 @@ impl Tester {
                             actual: String::from("<test passed, no fix applied>"),
                         });
                     }
-                    TestResult::Failed => {
+                    TestResult::Failed(_) => {
                         failures.push(FixFailure {
                             source: source.clone(),
                             expected: expect.expected.clone(),
@@ -704,6 +856,7 @@ impl Tester {
         }
         .to_string_lossy();
 
+        let count = result.len();
         let handler = GraphicalReportHandler::new()
             .with_links(false)
             .with_theme(GraphicalTheme::unicode_nocolor());
@@ -714,7 +867,7 @@ impl Tester {
             ));
             handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
         }
-        TestResult::Failed
+        TestResult::Failed(count)
     }
 
     fn find_rule(&self) -> &RuleEnum {
@@ -751,6 +904,16 @@ enum TestFailure {
         /// Rule configuration used in the test
         rule_config: Option<Value>,
     },
+    WrongErrorCount {
+        /// Test source code
+        source: String,
+        /// Rule configuration used in the test
+        rule_config: Option<Value>,
+        /// Number of diagnostics expected by the test case
+        expected: usize,
+        /// Number of diagnostics reported
+        actual: usize,
+    },
 }
 
 /// Format source code for display in test failure output.
@@ -839,6 +1002,16 @@ fn format_test_failures(reason: &str, failures: &[TestFailure]) -> String {
                     let _ = writeln!(output, "      config: {config_str}");
                 }
             }
+            TestFailure::WrongErrorCount { rule_config, source, expected, actual } => {
+                let formatted_source = format_test_source(source, 10);
+                let _ = writeln!(output, "  {:>2}. {formatted_source}", index + 1);
+                let _ = writeln!(output, "      expected {expected} error(s), but got {actual}");
+                if let Some(config) = &rule_config {
+                    // Format config compactly on one line if possible
+                    let config_str = serde_json::to_string(config).unwrap_or_default();
+                    let _ = writeln!(output, "      config: {config_str}");
+                }
+            }
         }
     }
     output
@@ -390,7 +390,6 @@ pub struct ParsedGeneralJestFnCall<'a> {
     pub kind: JestFnKind,
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: Cow<'a, str>,
-    #[expect(unused)]
     pub local: Cow<'a, str>,
 }
 
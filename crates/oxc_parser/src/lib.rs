@@ -89,7 +89,7 @@ pub mod lexer;
 
 use oxc_allocator::{Allocator, ArenaBox, ArenaVec, Dummy, GetAllocator};
 use oxc_ast::{
-    ast::{Expression, Program, Statement},
+    ast::{Comment, Expression, Program, Statement},
     builder::{AstBuilder, GetAstBuilder},
 };
 use oxc_diagnostics::{Diagnostics, OxcDiagnostic};
@@ -401,10 +401,20 @@ mod parser_parse {
         ///
         /// # Errors
         /// If the source code being parsed has syntax errors.
+        pub fn parse_expression(self) -> Result<Expression<'a>, Diagnostics> {
+            self.parse_expression_with_comments().map(|(expression, _)| expression)
+        }
+
+        /// Parse a single [`Expression`], and return it along with the comments in source text.
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors.
         //
         // # Implementation note
         // Dispatches via `Any`, same as `parse` does.
-        pub fn parse_expression(self) -> Result<Expression<'a>, Diagnostics> {
+        pub fn parse_expression_with_comments(
+            self,
+        ) -> Result<(Expression<'a>, ArenaVec<'a, Comment>), Diagnostics> {
             let config: &dyn Any = &self.config;
             if config.is::<NoTokensParserConfig>() {
                 parse_expression_with_no_tokens_config(
@@ -530,7 +540,7 @@ mod parser_parse {
         source_text: &'a str,
         source_type: SourceType,
         options: ParseOptions,
-    ) -> Result<Expression<'a>, Diagnostics> {
+    ) -> Result<(Expression<'a>, ArenaVec<'a, Comment>), Diagnostics> {
         ParserImpl::<NoTokensParserConfig>::new(
             allocator,
             source_text,
@@ -548,7 +558,7 @@ mod parser_parse {
         source_text: &'a str,
         source_type: SourceType,
         options: ParseOptions,
-    ) -> Result<Expression<'a>, Diagnostics> {
+    ) -> Result<(Expression<'a>, ArenaVec<'a, Comment>), Diagnostics> {
         ParserImpl::<TokensParserConfig>::new(
             allocator,
             source_text,
@@ -567,7 +577,7 @@ mod parser_parse {
         source_type: SourceType,
         options: ParseOptions,
         config: RuntimeParserConfig,
-    ) -> Result<Expression<'a>, Diagnostics> {
+    ) -> Result<(Expression<'a>, ArenaVec<'a, Comment>), Diagnostics> {
         ParserImpl::<RuntimeParserConfig>::new(
             allocator,
             source_text,
@@ -746,7 +756,9 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
         }
     }
 
-    pub fn parse_expression(mut self) -> Result<Expression<'a>, Diagnostics> {
+    pub fn parse_expression(
+        mut self,
+    ) -> Result<(Expression<'a>, ArenaVec<'a, Comment>), Diagnostics> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
         let expr = self.parse_expr();
@@ -758,7 +770,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
         if !errors.is_empty() {
             return Err(errors);
         }
-        Ok(expr)
+        Ok((expr, self.lexer.trivia_builder.comments))
     }
 
     #[expect(clippy::cast_possible_truncation)]
@@ -1057,6 +1069,303 @@ mod test {
         assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
     }
 
+    #[test]
+    fn estree_attached_comments() {
+        use oxc_estree::{CompactSerializer, ESTree};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "/* a */ foo(); // b\nfunction f() { /* c */ }\n// d\n";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let program = &ret.program;
+
+        let mut serializer = CompactSerializer::new(false, false)
+            .with_attached_comments(program, program.estree_comments());
+        program.serialize(&mut serializer);
+        let json = serializer.into_string();
+
+        let a = r#"{"type":"CommentBlock","value":" a ","start":0,"end":7}"#;
+        let b = r#"{"type":"CommentLine","value":" b","start":15,"end":19}"#;
+        let c = r#"{"type":"CommentBlock","value":" c ","start":35,"end":42}"#;
+        let d = r#"{"type":"CommentLine","value":" d","start":45,"end":49}"#;
+        // `foo();`: leading `a`, trailing `b`
+        assert!(json.contains(&format!(
+            r#""start":8,"end":14,"leadingComments":[{a}],"trailingComments":[{b}]}}"#
+        )));
+        // `function f() {}`: leading `b` (also trailing of previous statement), trailing `d`
+        assert!(json.contains(&format!(
+            r#""start":20,"end":44,"leadingComments":[{b}],"trailingComments":[{d}]}}"#
+        )));
+        // Function body contains no statements, so `c` is an inner comment
+        assert!(json.contains(&format!(r#""start":33,"end":44,"innerComments":[{c}]}}"#)));
+        assert_eq!(json.matches("Comments\":").count(), 5);
+    }
+
+    #[test]
+    fn estree_serialize_subtree() {
+        use oxc_ast::ast::{Expression, Statement};
+        use oxc_estree::{CompactSerializer, serialize_node};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let x;\nconst obj = { f: function g(a) { return a; } };\n";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+
+        let Statement::VariableDeclaration(decl) = &ret.program.body[1] else { unreachable!() };
+        let Some(Expression::ObjectExpression(obj)) = &decl.declarations[0].init else {
+            unreachable!()
+        };
+        let Expression::FunctionExpression(func) = &obj.properties[0].as_property().unwrap().value
+        else {
+            unreachable!()
+        };
+
+        let mut serializer = CompactSerializer::new(false, true);
+        serialize_node(&**func, &mut serializer);
+        let json = serializer.into_string();
+
+        // Spans are offsets in the whole source, not relative to the function
+        assert_eq!(&source[24..51], "function g(a) { return a; }");
+        assert!(json.starts_with(r#"{"type":"FunctionExpression","id":{"type":"Identifier","name":"g","start":33,"end":34,"range":[33,34]},"#));
+        assert!(json.contains(r#"{"type":"ReturnStatement","argument":{"type":"Identifier","name":"a","start":47,"end":48,"range":[47,48]},"start":40,"end":49,"range":[40,49]}"#));
+        assert!(json.ends_with(r#""start":24,"end":51,"range":[24,51]}"#));
+    }
+
+    /// Parse `source` and serialize the program to compact ESTree JSON.
+    fn to_estree_json(source: &str) -> String {
+        to_estree_json_with(source, |serializer| serializer)
+    }
+
+    /// Parse `source` and serialize the program with a [`CompactSerializer`] set up by `configure`.
+    ///
+    /// [`CompactSerializer`]: oxc_estree::CompactSerializer
+    fn to_estree_json_with(
+        source: &str,
+        configure: impl FnOnce(oxc_estree::CompactSerializer) -> oxc_estree::CompactSerializer,
+    ) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        assert!(ret.diagnostics.is_empty(), "{source}");
+        let mut serializer = configure(oxc_estree::CompactSerializer::new(false, false));
+        oxc_estree::serialize_node(&ret.program, &mut serializer);
+        serializer.into_string()
+    }
+
+    #[test]
+    fn estree_method_definition() {
+        let json =
+            to_estree_json("class C { constructor() {} static get x() {} [y]() {} set z(v) {} }");
+
+        let expected = [
+            r#""kind":"constructor","computed":false,"static":false"#,
+            r#""kind":"get","computed":false,"static":true"#,
+            r#""kind":"method","computed":true,"static":false"#,
+            r#""kind":"set","computed":false,"static":false"#,
+        ];
+        assert_eq!(
+            json.matches(r#"{"type":"MethodDefinition","#).count(),
+            expected.len(),
+            "{json}"
+        );
+        for expected in expected {
+            assert!(json.contains(expected), "expected `{expected}` in {json}");
+        }
+    }
+
+    #[test]
+    fn estree_super_and_this() {
+        let json = to_estree_json("class C extends B { m() { super.x(); return this; } }");
+
+        assert!(json.contains(r#""object":{"type":"Super","start":26,"end":31}"#), "{json}");
+        assert!(
+            json.contains(r#""argument":{"type":"ThisExpression","start":44,"end":48}"#),
+            "{json}"
+        );
+        assert!(!json.contains(r#""name":"super""#), "{json}");
+        assert!(!json.contains(r#""name":"this""#), "{json}");
+    }
+
+    #[test]
+    fn estree_function_flags() {
+        let json = to_estree_json(
+            "async function* f() {} (function () {}); async (x) => x; () => { return 1; };",
+        );
+        // `async function* f() {}`
+        assert!(
+            json.contains(
+                r#""type":"FunctionDeclaration","id":{"type":"Identifier","name":"f","start":16,"end":17},"generator":true,"async":true,"#
+            ),
+            "{json}"
+        );
+        assert!(json.contains(r#""expression":false,"start":0,"end":22}"#), "{json}");
+        // `function () {}`
+        assert!(
+            json.contains(
+                r#""type":"FunctionExpression","id":null,"generator":false,"async":false,"#
+            ),
+            "{json}"
+        );
+        assert!(json.contains(r#""expression":false,"start":24,"end":38}"#), "{json}");
+        // `async (x) => x`
+        assert!(
+            json.contains(r#""type":"ArrowFunctionExpression","expression":true,"async":true,"#),
+            "{json}"
+        );
+        assert!(json.contains(r#""id":null,"generator":false,"start":41,"end":55}"#), "{json}");
+        // `() => { return 1; }`
+        assert!(
+            json.contains(r#""type":"ArrowFunctionExpression","expression":false,"async":false,"#),
+            "{json}"
+        );
+        assert!(json.contains(r#""id":null,"generator":false,"start":57,"end":76}"#), "{json}");
+    }
+
+    #[test]
+    fn estree_export_all_exported() {
+        let json = to_estree_json(
+            "export * from 'x'; export * as ns from 'x'; export * as 'a b' from 'x';",
+        );
+
+        assert!(json.contains(r#"{"type":"ExportAllDeclaration","exported":null,"#), "{json}");
+        assert!(
+            json.contains(
+                r#"{"type":"ExportAllDeclaration","exported":{"type":"Identifier","name":"ns","start":31,"end":33},"#
+            ),
+            "{json}"
+        );
+        assert!(
+            json.contains(
+                r#"{"type":"ExportAllDeclaration","exported":{"type":"Literal","value":"a b","raw":"'a b'","start":56,"end":61},"#
+            ),
+            "{json}"
+        );
+    }
+
+    #[test]
+    fn estree_import_expression() {
+        let json = to_estree_json("import('x'); import('x', { with: { type: 'json' } });");
+
+        assert!(!json.contains("CallExpression"), "{json}");
+        assert!(
+            json.contains(
+                r#"{"type":"ImportExpression","source":{"type":"Literal","value":"x","raw":"'x'","start":7,"end":10},"options":null,"phase":null,"start":0,"end":11}"#
+            ),
+            "{json}"
+        );
+        assert!(
+            json.contains(
+                r#""options":{"type":"ObjectExpression","properties":[{"type":"Property","kind":"init","key":{"type":"Identifier","name":"with","start":27,"end":31},"value":{"type":"ObjectExpression","properties":[{"type":"Property","kind":"init","key":{"type":"Identifier","name":"type","start":35,"end":39},"value":{"type":"Literal","value":"json","raw":"'json'","start":41,"end":47},"#
+            ),
+            "{json}"
+        );
+        assert!(json.contains(r#""phase":null,"start":13,"end":52}"#), "{json}");
+    }
+
+    #[test]
+    fn estree_import_attributes() {
+        let attributes = |start: u32| {
+            let (key_end, value_start, value_end) = (start + 4, start + 6, start + 12);
+            format!(
+                r#""attributes":[{{"type":"ImportAttribute","key":{{"type":"Identifier","name":"type","start":{start},"end":{key_end}}},"value":{{"type":"Literal","value":"json","raw":"'json'","start":{value_start},"end":{value_end}}},"start":{start},"end":{value_end}}}]"#
+            )
+        };
+
+        let json = to_estree_json("import x from 'y' with { type: 'json' };");
+        assert!(json.contains(r#"{"type":"ImportDeclaration","#), "{json}");
+        assert!(json.contains(&attributes(25)), "{json}");
+
+        // Deprecated `assert` keyword produces the same `attributes` field
+        let json = to_estree_json("import x from 'y' assert { type: 'json' };");
+        assert!(json.contains(&attributes(27)), "{json}");
+        assert!(!json.contains("assertions"), "{json}");
+
+        let json = to_estree_json("export { x } from 'y' with { type: 'json' };");
+        assert!(json.contains(r#"{"type":"ExportNamedDeclaration","#), "{json}");
+        assert!(json.contains(&attributes(29)), "{json}");
+
+        let json = to_estree_json("export * from 'y' with { type: 'json' };");
+        assert!(json.contains(r#"{"type":"ExportAllDeclaration","#), "{json}");
+        assert!(json.contains(&attributes(25)), "{json}");
+
+        // No `with` clause
+        let json = to_estree_json("import x from 'y'; export { x } from 'y'; export * from 'y';");
+        assert_eq!(json.matches(r#""attributes":[]"#).count(), 3, "{json}");
+    }
+
+    #[test]
+    fn estree_field_renames() {
+        let renames = [("Program", "body", "statements")];
+        let json =
+            to_estree_json_with("x; { y; }", |serializer| serializer.with_field_renames(renames));
+        assert!(
+            json.starts_with(r#"{"type":"Program","statements":[{"type":"ExpressionStatement","#),
+            "{json}"
+        );
+        // `body` of other node types is not renamed
+        assert!(json.contains(r#"{"type":"BlockStatement","body":["#), "{json}");
+    }
+
+    #[test]
+    fn estree_infinite_numeric_literal() {
+        // `1e+400` is read back by `JSON.parse` as `Infinity`, which is what Acorn gives as `value`
+        let json = to_estree_json("1e999; -1e999;");
+        assert!(
+            json.contains(r#"{"type":"Literal","value":1e+400,"raw":"1e999","start":0,"end":5}"#),
+            "{json}"
+        );
+        assert!(
+            json.contains(r#"{"type":"Literal","value":1e+400,"raw":"1e999","start":8,"end":13}"#),
+            "{json}"
+        );
+    }
+
+    #[test]
+    fn estree_loc_columns() {
+        use oxc_estree::ColumnUnit;
+
+        let source = "x;\nlet s = 'é😀', t;";
+        let serialize = |column_base, column_unit| {
+            to_estree_json_with(source, |serializer| {
+                serializer.with_loc(source, column_base, column_unit)
+            })
+        };
+        let t_loc = |start_column, end_column| {
+            format!(
+                r#""name":"t","start":21,"end":22,"loc":{{"start":{{"line":2,"column":{start_column}}},"end":{{"line":2,"column":{end_column}}}}}"#
+            )
+        };
+
+        let json = serialize(0, ColumnUnit::Utf16);
+        assert!(
+            json.contains(
+                r#""type":"Program","body":[{"type":"ExpressionStatement","expression":{"type":"Identifier","name":"x","start":0,"end":1,"loc":{"start":{"line":1,"column":0},"end":{"line":1,"column":1}}}"#
+            ),
+            "{json}"
+        );
+        assert!(json.contains(&t_loc(15, 16)), "{json}");
+        assert!(serialize(1, ColumnUnit::Utf16).contains(&t_loc(16, 17)));
+        assert!(serialize(0, ColumnUnit::Utf8).contains(&t_loc(18, 19)));
+        assert!(serialize(1, ColumnUnit::Utf8).contains(&t_loc(19, 20)));
+        assert!(serialize(0, ColumnUnit::Chars).contains(&t_loc(14, 15)));
+        assert!(serialize(1, ColumnUnit::Chars).contains(&t_loc(15, 16)));
+
+        // `loc` is omitted by default
+        assert!(!to_estree_json(source).contains("\"loc\""));
+    }
+
+    #[test]
+    fn estree_compact_omit_null_fields() {
+        let source = "let a = [1, { b: 2 }];";
+        let full = to_estree_json(source);
+        let json = to_estree_json_with(source, |serializer| serializer.with_omit_null_fields(true));
+
+        assert!(!full.contains(' '), "{full}");
+        assert!(!json.contains(' '), "{json}");
+        assert!(!json.contains("null"), "{json}");
+        assert_eq!(json.len(), 670);
+        assert!(json.len() < full.len());
+    }
+
     #[test]
     fn unambiguous() {
         let allocator = Allocator::default();
@@ -3,7 +3,10 @@ import { describe, expect, it, test } from "vitest";
 
 import { parse, parseSync } from "../src-js/index.js";
 import type {
+  ExportNamedDeclaration,
   ExpressionStatement,
+  ImportDeclaration,
+  ImportSpecifier,
   ParserOptions,
   Program,
   TSTypeAliasDeclaration,
@@ -302,6 +305,28 @@ describe("parse", () => {
       });
     });
 
+    it("has `regex` with pattern and flags in alphabetical order", () => {
+      const ret = parseSync("test.js", "/ab+c/ig");
+      expect(ret.errors.length).toBe(0);
+      expect(ret.program.body.length).toBe(1);
+      expect(ret.program.body[0]).toEqual({
+        type: "ExpressionStatement",
+        start: 0,
+        end: 8,
+        expression: {
+          type: "Literal",
+          start: 0,
+          end: 8,
+          value: /ab+c/gi,
+          raw: "/ab+c/ig",
+          regex: {
+            pattern: "ab+c",
+            flags: "gi",
+          },
+        },
+      });
+    });
+
     it("has `value` as `null` when invalid regexp", () => {
       const ret = parseSync("test.js", "/+/");
       expect(ret.errors.length).toBe(0);
@@ -647,6 +672,236 @@ describe("parse", () => {
     });
   });
 
+  describe("decorators", () => {
+    it("are placed on the decorated class and method", () => {
+      const ret = parseSync("test.ts", "@dec class Foo { @m method(){} }");
+      expect(ret.errors.length).toBe(0);
+      expect(ret.program.body.length).toBe(1);
+      expect(ret.program.body[0]).toEqual({
+        type: "ClassDeclaration",
+        start: 0,
+        end: 32,
+        decorators: [
+          {
+            type: "Decorator",
+            start: 0,
+            end: 4,
+            expression: {
+              type: "Identifier",
+              start: 1,
+              end: 4,
+              decorators: [],
+              name: "dec",
+              optional: false,
+              typeAnnotation: null,
+            },
+          },
+        ],
+        id: {
+          type: "Identifier",
+          start: 11,
+          end: 14,
+          decorators: [],
+          name: "Foo",
+          optional: false,
+          typeAnnotation: null,
+        },
+        typeParameters: null,
+        superClass: null,
+        superTypeArguments: null,
+        implements: [],
+        body: {
+          type: "ClassBody",
+          start: 15,
+          end: 32,
+          body: [
+            {
+              type: "MethodDefinition",
+              start: 17,
+              end: 30,
+              decorators: [
+                {
+                  type: "Decorator",
+                  start: 17,
+                  end: 19,
+                  expression: {
+                    type: "Identifier",
+                    start: 18,
+                    end: 19,
+                    decorators: [],
+                    name: "m",
+                    optional: false,
+                    typeAnnotation: null,
+                  },
+                },
+              ],
+              key: {
+                type: "Identifier",
+                start: 20,
+                end: 26,
+                decorators: [],
+                name: "method",
+                optional: false,
+                typeAnnotation: null,
+              },
+              value: {
+                type: "FunctionExpression",
+                start: 26,
+                end: 30,
+                id: null,
+                generator: false,
+                async: false,
+                declare: false,
+                typeParameters: null,
+                params: [],
+                returnType: null,
+                body: { type: "BlockStatement", start: 28, end: 30, body: [] },
+                expression: false,
+              },
+              kind: "method",
+              computed: false,
+              static: false,
+              override: false,
+              optional: false,
+              accessibility: null,
+            },
+          ],
+        },
+        abstract: false,
+        declare: false,
+      });
+    });
+  });
+
+  describe("`AccessorProperty`", () => {
+    it("ESTree", () => {
+      const ret = parseSync("test.js", "class C { static accessor #x = 1; }");
+      expect(ret.errors.length).toBe(0);
+      // @ts-expect-error - ignore
+      expect(ret.program.body[0].body.body[0]).toEqual({
+        type: "AccessorProperty",
+        start: 10,
+        end: 33,
+        decorators: [],
+        key: { type: "PrivateIdentifier", start: 26, end: 28, name: "x" },
+        value: { type: "Literal", start: 31, end: 32, value: 1, raw: "1" },
+        computed: false,
+        static: true,
+      });
+    });
+
+    it("TS-ESTree", () => {
+      const ret = parseSync("test.ts", "class C { static accessor #x = 1; }");
+      expect(ret.errors.length).toBe(0);
+      // @ts-expect-error - ignore
+      expect(ret.program.body[0].body.body[0]).toEqual({
+        type: "AccessorProperty",
+        start: 10,
+        end: 33,
+        decorators: [],
+        key: { type: "PrivateIdentifier", start: 26, end: 28, name: "x" },
+        typeAnnotation: null,
+        value: { type: "Literal", start: 31, end: 32, value: 1, raw: "1" },
+        computed: false,
+        static: true,
+        override: false,
+        definite: false,
+        accessibility: null,
+        declare: false,
+        optional: false,
+        readonly: false,
+      });
+    });
+  });
+
+  describe("definite assignment assertion", () => {
+    it("`PropertyDefinition`", () => {
+      const ret = parseSync("test.ts", "class C { x!: number }");
+      expect(ret.errors.length).toBe(0);
+      // @ts-expect-error - ignore
+      expect(ret.program.body[0].body.body[0]).toEqual({
+        type: "PropertyDefinition",
+        start: 10,
+        end: 20,
+        decorators: [],
+        key: {
+          type: "Identifier",
+          start: 10,
+          end: 11,
+          decorators: [],
+          name: "x",
+          optional: false,
+          typeAnnotation: null,
+        },
+        typeAnnotation: {
+          type: "TSTypeAnnotation",
+          start: 12,
+          end: 20,
+          typeAnnotation: { type: "TSNumberKeyword", start: 14, end: 20 },
+        },
+        value: null,
+        computed: false,
+        static: false,
+        declare: false,
+        override: false,
+        optional: false,
+        definite: true,
+        readonly: false,
+        accessibility: null,
+      });
+    });
+
+    it("`VariableDeclarator`", () => {
+      const ret = parseSync("test.ts", "let y!: number;");
+      expect(ret.errors.length).toBe(0);
+      // @ts-expect-error - ignore
+      expect(ret.program.body[0].declarations[0]).toEqual({
+        type: "VariableDeclarator",
+        start: 4,
+        end: 14,
+        id: {
+          type: "Identifier",
+          start: 4,
+          end: 14,
+          decorators: [],
+          name: "y",
+          optional: false,
+          typeAnnotation: {
+            type: "TSTypeAnnotation",
+            start: 6,
+            end: 14,
+            typeAnnotation: { type: "TSNumberKeyword", start: 8, end: 14 },
+          },
+        },
+        init: null,
+        definite: true,
+      });
+    });
+  });
+
+  describe("`importKind` and `exportKind`", () => {
+    it("import with type-only specifiers", () => {
+      const ret = parseSync("test.ts", "import { a, type b } from 'x'; import type { c } from 'x';");
+      expect(ret.errors.length).toBe(0);
+      const [mixed, typeOnly] = ret.program.body as ImportDeclaration[];
+      expect(mixed.importKind).toBe("value");
+      const specifiers = mixed.specifiers as ImportSpecifier[];
+      expect(specifiers.map((s) => s.importKind)).toEqual(["value", "type"]);
+      expect(typeOnly.importKind).toBe("type");
+      expect((typeOnly.specifiers[0] as ImportSpecifier).importKind).toBe("value");
+    });
+
+    it("export with type-only specifiers", () => {
+      const ret = parseSync("test.ts", "export { a, type b } from 'x'; export type { c } from 'x';");
+      expect(ret.errors.length).toBe(0);
+      const [mixed, typeOnly] = ret.program.body as ExportNamedDeclaration[];
+      expect(mixed.exportKind).toBe("value");
+      expect(mixed.specifiers.map((s) => s.exportKind)).toEqual(["value", "type"]);
+      expect(typeOnly.exportKind).toBe("type");
+      expect(typeOnly.specifiers[0].exportKind).toBe("value");
+    });
+  });
+
   describe("hashbang", () => {
     it("is `null` when no hashbang", () => {
       const ret = parseSync("test.js", "let x;");
@@ -688,6 +943,22 @@ describe("parse", () => {
       expect(ret.comments).toHaveLength(1);
       expect(ret.comments[0].value).toBe(" foo");
     });
+
+    it("is included in `Program` span in JS files", () => {
+      const code = "#!/usr/bin/env node\nlet x;\n\n  \n";
+      const ret = parseSync("test.js", code);
+      expect(ret.errors.length).toBe(0);
+      expect(ret.program.start).toBe(0);
+      expect(ret.program.end).toBe(code.length);
+    });
+
+    it("is excluded from `Program` span in TS files", () => {
+      const code = "#!/usr/bin/env node\nlet x;\n\n  \n";
+      const ret = parseSync("test.ts", code);
+      expect(ret.errors.length).toBe(0);
+      expect(ret.program.start).toBe(20);
+      expect(ret.program.end).toBe(code.length);
+    });
   });
 
   describe("preserveParens", () => {
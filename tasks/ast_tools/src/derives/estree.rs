@@ -378,15 +378,20 @@ fn generate_body_for_struct(struct_def: &StructDef, schema: &Schema) -> TokenStr
         quote!()
     };
 
+    let oxc_metadata = g.generate_stmt_for_oxc_metadata();
     let stmts = g.stmts;
     quote! {
         let mut state = serializer.serialize_struct();
         #type_field
         #stmts
+        #oxc_metadata
         state.end();
     }
 }
 
+/// Names of struct fields containing semantic IDs, which are serialized in `_oxc` metadata field.
+const OXC_METADATA_FIELD_NAMES: [&str; 3] = ["scope_id", "symbol_id", "reference_id"];
+
 /// Generator for stmts to serialize fields of a struct.
 ///
 /// Recursively enters any flattened fields which contain a struct,
@@ -399,6 +404,9 @@ struct StructSerializerGenerator<'s> {
     /// `true` if a `type` field should be added.
     /// `false` one already exists (or if `#[estree(no_type)]` attr on struct).
     add_type_field: bool,
+    /// Semantic ID fields (`scope_id`, `symbol_id`, `reference_id`) found in the struct,
+    /// to be serialized in the `_oxc` metadata field
+    oxc_metadata_fields: Vec<(&'static str, TokenStream)>,
     /// Crate in which the `ESTree` impl for the type will be generated
     krate: &'s str,
     /// Schema
@@ -408,11 +416,20 @@ struct StructSerializerGenerator<'s> {
 impl<'s> StructSerializerGenerator<'s> {
     /// Create new [`StructSerializerGenerator`].
     fn new(add_type_field: bool, krate: &'s str, schema: &'s Schema) -> Self {
-        Self { stmts: quote!(), add_type_field, krate, schema }
+        Self { stmts: quote!(), add_type_field, oxc_metadata_fields: vec![], krate, schema }
     }
 
     /// Generate code to serialize all fields in a struct.
     fn generate_stmts_for_struct(&mut self, struct_def: &StructDef, self_path: &TokenStream) {
+        // Semantic ID fields are skipped in ESTree output, so they're not in `field_indices`
+        for field in &struct_def.fields {
+            if let Some(&name) = OXC_METADATA_FIELD_NAMES.iter().find(|&&name| name == field.name())
+            {
+                let field_name_ident = field.ident();
+                self.oxc_metadata_fields.push((name, quote!( #self_path.#field_name_ident.get() )));
+            }
+        }
+
         for &field_index in &struct_def.estree.field_indices {
             let field_index = field_index as usize;
             if let Some(field) = struct_def.fields.get(field_index) {
@@ -544,6 +561,33 @@ impl<'s> StructSerializerGenerator<'s> {
         });
     }
 
+    /// Generate code to serialize `_oxc` metadata field, if struct has any semantic ID fields.
+    fn generate_stmt_for_oxc_metadata(&self) -> TokenStream {
+        if self.oxc_metadata_fields.is_empty() {
+            return quote!();
+        }
+
+        assert!(
+            self.krate == "oxc_ast",
+            "Semantic ID fields are only supported in `oxc_ast` crate: {}",
+            self.krate
+        );
+
+        let fields = OXC_METADATA_FIELD_NAMES.iter().map(|&name| {
+            let field_ident = create_safe_ident(name);
+            let value = self
+                .oxc_metadata_fields
+                .iter()
+                .find(|(field_name, _)| *field_name == name)
+                .map_or_else(|| quote!(None), |(_, value)| value.clone());
+            quote!( #field_ident: #value )
+        });
+
+        quote! {
+            state.serialize_oxc_metadata(&crate::serialize::basic::OxcMetadata { #(#fields),* });
+        }
+    }
+
     fn generate_stmt_for_added_field(
         &mut self,
         field_name: &str,